@@ -2,8 +2,11 @@
 
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
 use nonbox::{
+    f30,
     f62::{self, Float62},
-    f64,
+    f64::{self, Float64},
+    nan_box::NanBox,
+    offset::OffsetBox,
 };
 
 const ITERATION_COUNT: usize = 10000;
@@ -23,12 +26,12 @@ fn map<I, T>(
     });
 }
 
-fn binary<T>(
+fn binary<V: Copy, T>(
     criterion: &mut Criterion,
     name: &str,
-    left: &[Float62],
-    right: &[Float62],
-    operate: impl Fn(Float62, Float62) -> T,
+    left: &[V],
+    right: &[V],
+    operate: impl Fn(V, V) -> T,
 ) {
     criterion.bench_function(name, |bencher| {
         bencher.iter(|| {
@@ -39,11 +42,11 @@ fn binary<T>(
     });
 }
 
-fn unary<T>(
+fn unary<V: Copy, T>(
     criterion: &mut Criterion,
     name: &str,
-    values: &[Float62],
-    operate: impl Fn(Float62) -> T,
+    values: &[V],
+    operate: impl Fn(V) -> T,
 ) {
     criterion.bench_function(name, |bencher| {
         bencher.iter(|| {
@@ -100,6 +103,61 @@ fn sum(criterion: &mut Criterion) {
             black_box(sum);
         })
     });
+
+    criterion.bench_function("sum_f62_unbox_integer_unchecked", |bencher| {
+        let xs = (0..ITERATION_COUNT as i64)
+            .map(Float62::from_integer)
+            .collect::<Vec<_>>();
+
+        bencher.iter(|| {
+            let mut sum = Float62::default();
+
+            for x in &xs {
+                sum = Float62::from_integer(
+                    sum.to_integer_unchecked() + black_box(*x).to_integer_unchecked(),
+                );
+            }
+
+            black_box(sum);
+        })
+    });
+
+    criterion.bench_function("sum_f62_unbox_integer_unchecked_hint", |bencher| {
+        let xs = (0..ITERATION_COUNT as i64)
+            .map(Float62::from_integer)
+            .collect::<Vec<_>>();
+
+        bencher.iter(|| {
+            let mut sum = Float62::default();
+
+            for x in &xs {
+                // SAFETY: The numbers are integers.
+                sum = Float62::from_integer(unsafe {
+                    sum.to_integer_unchecked_hint() + black_box(*x).to_integer_unchecked_hint()
+                });
+            }
+
+            black_box(sum);
+        })
+    });
+
+    criterion.bench_function("sum_f62_add_integers_unchecked", |bencher| {
+        let xs = (0..ITERATION_COUNT as i64)
+            .map(Float62::from_integer)
+            .collect::<Vec<_>>();
+
+        bencher.iter(|| {
+            let mut sum = Float62::default();
+
+            for x in &xs {
+                // SAFETY: The numbers are integers and their sum does not
+                // overflow.
+                sum = unsafe { sum.add_integers_unchecked(black_box(*x)) };
+            }
+
+            black_box(sum);
+        })
+    });
 }
 
 fn f64_box(criterion: &mut Criterion) {
@@ -135,6 +193,33 @@ fn f64_box(criterion: &mut Criterion) {
     );
 }
 
+fn f30_box(criterion: &mut Criterion) {
+    map(
+        criterion,
+        "f30_box_integer",
+        |index| index as i32,
+        f30::box_integer,
+    );
+    map(
+        criterion,
+        "f30_unbox_integer",
+        |index| index as u32,
+        f30::unbox_integer,
+    );
+    map(
+        criterion,
+        "f30_box_float",
+        |index| f32::from_bits(index as u32),
+        f30::box_float,
+    );
+    map(
+        criterion,
+        "f30_unbox_float",
+        |index| index as u32,
+        f30::unbox_float,
+    );
+}
+
 fn f62_box(criterion: &mut Criterion) {
     map(
         criterion,
@@ -221,6 +306,99 @@ fn f62_box(criterion: &mut Criterion) {
     );
 }
 
+fn slice<I, O: Clone>(
+    criterion: &mut Criterion,
+    name: &str,
+    input: &[I],
+    output: O,
+    function: impl Fn(&[I], &mut O),
+) {
+    criterion.bench_function(name, |bencher| {
+        let mut output = output.clone();
+
+        bencher.iter(|| {
+            function(black_box(input), &mut output);
+            black_box(&output);
+        })
+    });
+}
+
+fn f62_slice(criterion: &mut Criterion) {
+    let floats = (0..ITERATION_COUNT)
+        .map(|x| x as f64 * 0.5)
+        .collect::<Vec<_>>();
+    let integers = (0..ITERATION_COUNT as i64).collect::<Vec<_>>();
+    let numbers = integers
+        .iter()
+        .copied()
+        .map(Float62::from_integer)
+        .collect::<Vec<_>>();
+    let default_numbers = vec![Float62::default(); ITERATION_COUNT];
+
+    slice(
+        criterion,
+        "f62_box_floats",
+        &floats,
+        default_numbers.clone(),
+        |input, output| f62::box_floats(input, output),
+    );
+    slice(
+        criterion,
+        "f62_box_integers",
+        &integers,
+        default_numbers.clone(),
+        |input, output| f62::box_integers(input, output),
+    );
+    slice(
+        criterion,
+        "f62_unbox_integers",
+        &numbers,
+        vec![0; ITERATION_COUNT],
+        |input, output| {
+            black_box(f62::unbox_integers(input, output));
+        },
+    );
+    slice(
+        criterion,
+        "f62_classify_mask",
+        &numbers,
+        vec![0; ITERATION_COUNT.div_ceil(64)],
+        |input, output| f62::classify_mask(input, f62::Float62Tag::Integer, output),
+    );
+    slice(
+        criterion,
+        "f62_add_slices",
+        &numbers,
+        default_numbers.clone(),
+        |input, output| f62::add_slices(input, input, output),
+    );
+    slice(
+        criterion,
+        "f62_mul_slices",
+        &numbers,
+        default_numbers.clone(),
+        |input, output| f62::mul_slices(input, input, output),
+    );
+    slice(
+        criterion,
+        "f62_to_f64_boxes",
+        &numbers,
+        vec![Float64::default(); ITERATION_COUNT],
+        |input, output| f62::to_f64_boxes(input, output).unwrap(),
+    );
+    slice(
+        criterion,
+        "f62_from_f64_boxes",
+        &integers
+            .iter()
+            .copied()
+            .map(Float64::from_integer)
+            .collect::<Vec<_>>(),
+        default_numbers,
+        |input, output| f62::from_f64_boxes(input, output).unwrap(),
+    );
+}
+
 fn f62_operation(criterion: &mut Criterion) {
     let integers = (0..ITERATION_COUNT as i64)
         .map(Float62::from_integer)
@@ -254,6 +432,14 @@ fn f62_operation(criterion: &mut Criterion) {
         &integers,
         |x, y| x + y,
     );
+    binary(
+        criterion,
+        "f62_add_integer_unchecked",
+        &integers,
+        &integers,
+        // SAFETY: The numbers are integers and their sums do not overflow.
+        |x, y| unsafe { x.add_integers_unchecked(y) },
+    );
     binary(
         criterion,
         "f62_sub_integer",
@@ -347,6 +533,76 @@ fn f62_operation(criterion: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, sum, f64_box, f62_box, f62_operation);
+fn nan_box_operation<T: NanBox>(criterion: &mut Criterion, prefix: &str) {
+    let integers = (0..ITERATION_COUNT as i64)
+        .map(T::from_integer)
+        .collect::<Vec<_>>();
+    let floats = (0..ITERATION_COUNT)
+        .map(|x| T::from_float(x as f64 + 0.5))
+        .collect::<Vec<_>>();
+
+    binary(
+        criterion,
+        &format!("{prefix}_add_integer"),
+        &integers,
+        &integers,
+        |x, y| x + y,
+    );
+    binary(
+        criterion,
+        &format!("{prefix}_mul_integer"),
+        &integers,
+        &integers,
+        |x, y| x * y,
+    );
+    binary(
+        criterion,
+        &format!("{prefix}_add_float"),
+        &floats,
+        &floats,
+        |x, y| x + y,
+    );
+    binary(
+        criterion,
+        &format!("{prefix}_mul_float"),
+        &floats,
+        &floats,
+        |x, y| x * y,
+    );
+    binary(
+        criterion,
+        &format!("{prefix}_add_mixed"),
+        &integers,
+        &floats,
+        |x, y| x + y,
+    );
+    binary(
+        criterion,
+        &format!("{prefix}_cmp_mixed"),
+        &integers,
+        &floats,
+        |x, y| x.partial_cmp(&y),
+    );
+    unary(criterion, &format!("{prefix}_classify"), &floats, |x| {
+        x.classify()
+    });
+}
+
+fn nan_box(criterion: &mut Criterion) {
+    nan_box_operation::<Float62>(criterion, "nan_box_f62");
+    nan_box_operation::<Float64>(criterion, "nan_box_f64");
+    nan_box_operation::<OffsetBox>(criterion, "nan_box_offset");
+}
+
+criterion_group!(
+    benches,
+    sum,
+    f64_box,
+    f30_box,
+    f62_box,
+    f62_slice,
+    f62_operation,
+    nan_box
+);
 
 criterion_main!(benches);
@@ -0,0 +1,714 @@
+//! NaN boxing for 30-bit floating-point numbers encompassing 31-bit integers,
+//! 29-bit payloads, and infinities and NaN.
+
+use core::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+};
+
+const ROTATION_COUNT: u32 = 3;
+
+const MANTISSA_WIDTH: u32 = 23;
+const EXPONENT_MASK: u32 = (1 << 8) - 1;
+const MINIMUM_EXPONENT: u32 = 0x60;
+const MAXIMUM_EXPONENT: u32 = 0x9f;
+
+const SPECIAL_TAG: u32 = 0b101;
+const NAN: u32 = SPECIAL_TAG;
+const POSITIVE_INFINITY: u32 = (1 << 3) | SPECIAL_TAG;
+const NEGATIVE_INFINITY: u32 = (2 << 3) | SPECIAL_TAG;
+
+/// Boxes a 31-bit signed integer.
+#[inline]
+pub const fn box_integer(integer: i32) -> u32 {
+    (integer << 1) as _
+}
+
+/// Unboxes a 31-bit signed integer.
+#[inline]
+pub const fn unbox_integer(number: u32) -> Option<i32> {
+    if is_integer(number) {
+        Some(unbox_integer_unchecked(number))
+    } else {
+        None
+    }
+}
+
+/// Unboxes a 31-bit signed integer without any type check.
+#[inline]
+pub const fn unbox_integer_unchecked(number: u32) -> i32 {
+    number as i32 >> 1
+}
+
+/// Returns `true` if a number is an integer.
+#[inline]
+pub const fn is_integer(number: u32) -> bool {
+    number & 1 == 0
+}
+
+/// Boxes a 29-bit payload.
+#[inline]
+pub const fn box_payload(payload: u32) -> u32 {
+    (payload << 3) | 1
+}
+
+/// Unboxes a 29-bit payload.
+#[inline]
+pub const fn unbox_payload(number: u32) -> Option<u32> {
+    if is_payload(number) {
+        Some(unbox_payload_unchecked(number))
+    } else {
+        None
+    }
+}
+
+/// Unboxes a 29-bit payload without any type check.
+#[inline]
+pub const fn unbox_payload_unchecked(number: u32) -> u32 {
+    number >> 3
+}
+
+/// Returns `true` if a number is a payload.
+#[inline]
+pub const fn is_payload(number: u32) -> bool {
+    number & 0b111 == 1
+}
+
+/// Boxes a 32-bit floating-point number.
+#[inline]
+pub const fn box_float(number: f32) -> u32 {
+    if number == 0.0 {
+        0
+    } else if number.is_nan() {
+        NAN
+    } else if number == f32::INFINITY {
+        POSITIVE_INFINITY
+    } else if number == f32::NEG_INFINITY {
+        NEGATIVE_INFINITY
+    } else {
+        let bits = number.to_bits();
+        let exponent = bits >> MANTISSA_WIDTH & EXPONENT_MASK;
+
+        if exponent < MINIMUM_EXPONENT {
+            0
+        } else if exponent > MAXIMUM_EXPONENT {
+            if number < 0.0 {
+                NEGATIVE_INFINITY
+            } else {
+                POSITIVE_INFINITY
+            }
+        } else {
+            bits.rotate_left(ROTATION_COUNT) | 0b11
+        }
+    }
+}
+
+/// Unboxes a 32-bit floating-point number.
+#[inline]
+pub const fn unbox_float(number: u32) -> Option<f32> {
+    if is_float(number) {
+        Some(unbox_float_unchecked(number))
+    } else if is_nan(number) {
+        Some(f32::NAN)
+    } else if number == POSITIVE_INFINITY {
+        Some(f32::INFINITY)
+    } else if number == NEGATIVE_INFINITY {
+        Some(f32::NEG_INFINITY)
+    } else {
+        None
+    }
+}
+
+/// Unboxes a 32-bit floating-point number without any type check.
+#[inline]
+pub const fn unbox_float_unchecked(number: u32) -> f32 {
+    let exponent_tail = 2 - (number >> 31);
+
+    f32::from_bits((number & !0b11 | exponent_tail).rotate_right(ROTATION_COUNT))
+}
+
+/// Returns `true` if a number is a 30-bit floating-point number.
+#[inline]
+pub const fn is_float(number: u32) -> bool {
+    number & 0b11 == 0b11
+}
+
+/// Returns `true` if a number is an infinity.
+#[inline]
+pub const fn is_infinite(number: u32) -> bool {
+    number == POSITIVE_INFINITY || number == NEGATIVE_INFINITY
+}
+
+/// Returns `true` if a number is NaN.
+#[inline]
+pub const fn is_nan(number: u32) -> bool {
+    number == NAN
+}
+
+/// A 30-bit floating-point number.
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct Float30(u32);
+
+impl Float30 {
+    /// Creates a 30-bit floating-point number from its raw representation.
+    #[inline]
+    pub const fn from_bits(number: u32) -> Self {
+        Self(number)
+    }
+
+    /// Returns a raw representation.
+    #[inline]
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+
+    /// Creates a 30-bit floating-point number from a payload.
+    #[inline]
+    pub const fn from_payload(payload: u32) -> Self {
+        Self::from_bits(box_payload(payload))
+    }
+
+    /// Creates a 30-bit floating-point number from an integer.
+    #[inline]
+    pub const fn from_integer(integer: i32) -> Self {
+        Self::from_bits(box_integer(integer))
+    }
+
+    /// Creates a 30-bit floating-point number from a 32-bit floating-point
+    /// number.
+    #[inline]
+    pub const fn from_float(number: f32) -> Self {
+        Self::from_bits(box_float(number))
+    }
+
+    /// Returns a payload.
+    #[inline]
+    pub const fn to_payload(self) -> Option<u32> {
+        unbox_payload(self.0)
+    }
+
+    /// Returns a payload without any type check.
+    #[inline]
+    pub const fn to_payload_unchecked(self) -> u32 {
+        unbox_payload_unchecked(self.0)
+    }
+
+    /// Returns an integer.
+    #[inline]
+    pub const fn to_integer(self) -> Option<i32> {
+        unbox_integer(self.0)
+    }
+
+    /// Returns an integer without any type check.
+    #[inline]
+    pub const fn to_integer_unchecked(self) -> i32 {
+        unbox_integer_unchecked(self.0)
+    }
+
+    /// Returns a 32-bit floating-point number.
+    #[inline]
+    pub const fn to_float(self) -> Option<f32> {
+        unbox_float(self.0)
+    }
+
+    /// Returns a 32-bit floating-point number without any type check.
+    #[inline]
+    pub const fn to_float_unchecked(self) -> f32 {
+        unbox_float_unchecked(self.0)
+    }
+
+    /// Returns `true` if this number is an infinity.
+    #[inline]
+    pub const fn is_infinite(self) -> bool {
+        is_infinite(self.0)
+    }
+
+    /// Returns `true` if this number is NaN.
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        is_nan(self.0)
+    }
+
+    #[inline]
+    const fn to_number(self) -> Result<i32, f32> {
+        if let Some(integer) = self.to_integer() {
+            Ok(integer)
+        } else if let Some(float) = self.to_float() {
+            Err(float)
+        } else {
+            Err(f32::NAN)
+        }
+    }
+}
+
+fn operate_float(lhs: Float30, rhs: Float30, operate: fn(f32, f32) -> f32) -> Float30 {
+    Float30::from_float(match (lhs.to_number(), rhs.to_number()) {
+        (Ok(_), Ok(_)) => unreachable!(),
+        (Ok(x), Err(y)) => operate(x as f32, y),
+        (Err(x), Ok(y)) => operate(x, y as f32),
+        (Err(x), Err(y)) => operate(x, y),
+    })
+}
+
+macro_rules! operate {
+    ($lhs:ident, $rhs:ident, $operate:ident, $wrapping_operate:ident) => {{
+        let (Some(x), Some(y)) = ($lhs.to_integer(), $rhs.to_integer()) else {
+            return operate_float($lhs, $rhs, f32::$operate);
+        };
+
+        Self::from_integer(x.$wrapping_operate(y))
+    }};
+}
+
+impl Add for Float30 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        operate!(self, rhs, add, wrapping_add)
+    }
+}
+
+impl Sub for Float30 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        operate!(self, rhs, sub, wrapping_sub)
+    }
+}
+
+impl Mul for Float30 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        operate!(self, rhs, mul, wrapping_mul)
+    }
+}
+
+impl Div for Float30 {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        let (Some(x), Some(y)) = (self.to_integer(), rhs.to_integer()) else {
+            return operate_float(self, rhs, f32::div);
+        };
+
+        if y != 0 && x % y == 0 {
+            Self::from_integer(x / y)
+        } else {
+            Self::from_float(x as f32 / y as f32)
+        }
+    }
+}
+
+impl Rem for Float30 {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, rhs: Self) -> Self::Output {
+        let (Some(x), Some(y)) = (self.to_integer(), rhs.to_integer()) else {
+            return operate_float(self, rhs, f32::rem);
+        };
+
+        if y == 0 {
+            Self::from_float(f32::NAN)
+        } else {
+            Self::from_integer(x % y)
+        }
+    }
+}
+
+impl Float30 {
+    /// Calculates the remainder of dividing this number by another number,
+    /// returning `None` when both numbers are integers and the divisor is
+    /// zero.
+    #[inline]
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        let (Some(x), Some(y)) = (self.to_integer(), rhs.to_integer()) else {
+            return Some(self % rhs);
+        };
+
+        Some(Self::from_integer(x.checked_rem(y)?))
+    }
+}
+
+impl AddAssign for Float30 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Float30 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Float30 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Float30 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Neg for Float30 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        match self.to_number() {
+            Ok(x) => Self::from_integer(-x),
+            Err(x) => Self::from_float(-x),
+        }
+    }
+}
+
+impl Display for Float30 {
+    #[inline]
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(integer) = self.to_integer() {
+            write!(formatter, "{integer}")
+        } else if let Some(float) = self.to_float() {
+            write!(formatter, "{float}")
+        } else {
+            write!(formatter, "0x{:x}", self.to_payload_unchecked())
+        }
+    }
+}
+
+fn compare_integer_and_float(integer: i32, float: f32) -> Option<Ordering> {
+    let rounded = integer as f32;
+
+    match rounded.partial_cmp(&float)? {
+        Ordering::Equal => integer.partial_cmp(&(rounded as i32)),
+        ordering => Some(ordering),
+    }
+}
+
+impl PartialEq for Float30 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Float30 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.0 == other.0 {
+            return (!self.is_nan()).then_some(Ordering::Equal);
+        }
+
+        match (self.to_number(), other.to_number()) {
+            (Ok(x), Ok(y)) => x.partial_cmp(&y),
+            (Ok(x), Err(y)) => compare_integer_and_float(x, y),
+            (Err(x), Ok(y)) => compare_integer_and_float(y, x).map(Ordering::reverse),
+            (Err(x), Err(y)) => x.partial_cmp(&y),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    const INTEGER_LIMIT: i32 = 1 << 30;
+
+    #[test]
+    fn integer() {
+        assert!(is_integer(box_integer(0)));
+        assert_eq!(unbox_integer(box_integer(0)), Some(0));
+        assert_eq!(unbox_integer(box_integer(1)), Some(1));
+        assert_eq!(unbox_integer(box_integer(-1)), Some(-1));
+        assert_eq!(unbox_integer(box_integer(42)), Some(42));
+        assert_eq!(unbox_integer(box_integer(-42)), Some(-42));
+        assert_eq!(
+            unbox_integer(box_integer(INTEGER_LIMIT - 1)),
+            Some(INTEGER_LIMIT - 1)
+        );
+        assert_eq!(
+            unbox_integer(box_integer(-INTEGER_LIMIT)),
+            Some(-INTEGER_LIMIT)
+        );
+    }
+
+    #[test]
+    fn payload() {
+        assert!(is_payload(box_payload(0)));
+        assert_eq!(unbox_payload(box_payload(0)), Some(0));
+        assert_eq!(unbox_payload(box_payload(1)), Some(1));
+        assert_eq!(unbox_payload(box_payload(42)), Some(42));
+    }
+
+    #[test]
+    fn maximum_payload() {
+        let maximum = (1 << 29) - 1;
+
+        assert!(is_payload(box_payload(maximum)));
+        assert_eq!(unbox_payload(box_payload(maximum)), Some(maximum));
+        assert!(!is_infinite(box_payload(maximum)));
+        assert!(!is_nan(box_payload(maximum)));
+    }
+
+    #[test]
+    fn f30() {
+        assert!(is_float(box_float(1.0)));
+        assert_eq!(unbox_float(box_float(0.0)), None);
+        assert_eq!(unbox_float(box_float(1.0)), Some(1.0));
+        assert_eq!(unbox_float(box_float(-1.0)), Some(-1.0));
+        assert_eq!(unbox_float(box_float(42.0)), Some(42.0));
+        assert_eq!(unbox_float(box_float(-42.0)), Some(-42.0));
+        assert_eq!(unbox_float(box_float(4.2)), Some(4.2));
+    }
+
+    #[test]
+    fn keep_float_within_exponent_range() {
+        let maximum = f32::from_bits(MAXIMUM_EXPONENT << MANTISSA_WIDTH);
+        let minimum = f32::from_bits(MINIMUM_EXPONENT << MANTISSA_WIDTH);
+
+        assert_eq!(unbox_float(box_float(maximum)), Some(maximum));
+        assert_eq!(unbox_float(box_float(-maximum)), Some(-maximum));
+        assert_eq!(unbox_float(box_float(minimum)), Some(minimum));
+        assert_eq!(unbox_float(box_float(-minimum)), Some(-minimum));
+    }
+
+    #[test]
+    fn saturate_to_infinity_on_overflow() {
+        let overflow = f32::from_bits((MAXIMUM_EXPONENT + 1) << MANTISSA_WIDTH);
+
+        assert_eq!(unbox_float(box_float(overflow)), Some(f32::INFINITY));
+        assert_eq!(unbox_float(box_float(-overflow)), Some(f32::NEG_INFINITY));
+        assert_eq!(unbox_float(box_float(f32::MAX)), Some(f32::INFINITY));
+        assert_eq!(unbox_float(box_float(f32::MIN)), Some(f32::NEG_INFINITY));
+    }
+
+    #[test]
+    fn flush_to_zero_on_underflow() {
+        let underflow = f32::from_bits((MINIMUM_EXPONENT - 1) << MANTISSA_WIDTH);
+
+        assert_eq!(box_float(underflow), 0);
+        assert_eq!(box_float(-underflow), 0);
+        assert_eq!(box_float(f32::MIN_POSITIVE), 0);
+        assert_eq!(unbox_integer(box_float(underflow)), Some(0));
+    }
+
+    #[test]
+    fn negative_zero() {
+        assert_eq!(box_float(-0.0), box_float(0.0));
+        assert_eq!(unbox_integer(box_float(-0.0)), Some(0));
+        assert_eq!(unbox_float(box_float(-0.0)), None);
+    }
+
+    #[test]
+    fn infinity() {
+        assert!(is_infinite(box_float(f32::INFINITY)));
+        assert!(is_infinite(box_float(f32::NEG_INFINITY)));
+        assert_ne!(box_float(f32::INFINITY), box_float(f32::NEG_INFINITY));
+        assert_eq!(unbox_float(box_float(f32::INFINITY)), Some(f32::INFINITY));
+        assert_eq!(
+            unbox_float(box_float(f32::NEG_INFINITY)),
+            Some(f32::NEG_INFINITY)
+        );
+
+        for number in [box_float(f32::INFINITY), box_float(f32::NEG_INFINITY)] {
+            assert!(!is_nan(number));
+            assert!(!is_integer(number));
+            assert!(!is_payload(number));
+            assert!(!is_float(number));
+        }
+    }
+
+    #[test]
+    fn nan() {
+        let number = box_float(f32::NAN);
+
+        assert!(is_nan(number));
+        assert!(unbox_float(number).unwrap().is_nan());
+        assert_eq!(box_float(f32::NAN), box_float(-f32::NAN));
+        assert!(!is_infinite(number));
+        assert!(!is_integer(number));
+        assert!(!is_payload(number));
+        assert!(!is_float(number));
+    }
+
+    mod float30 {
+        use super::*;
+
+        #[test]
+        fn default() {
+            assert_eq!(Float30::default(), Float30::from_integer(0));
+            assert_eq!(Float30::default(), Float30::from_float(0.0));
+        }
+
+        #[test]
+        fn add() {
+            assert_eq!(
+                Float30::from_integer(2) + Float30::from_integer(3),
+                Float30::from_integer(5)
+            );
+            assert_eq!(
+                Float30::from_integer(2) + Float30::from_float(3.0),
+                Float30::from_float(5.0)
+            );
+            assert_eq!(
+                Float30::from_float(2.0) + Float30::from_float(3.5),
+                Float30::from_float(5.5)
+            );
+        }
+
+        #[test]
+        fn sub() {
+            assert_eq!(
+                Float30::from_integer(2) - Float30::from_integer(3),
+                Float30::from_integer(-1)
+            );
+            assert_eq!(
+                Float30::from_float(2.0) - Float30::from_integer(3),
+                Float30::from_float(-1.0)
+            );
+        }
+
+        #[test]
+        fn mul() {
+            assert_eq!(
+                Float30::from_integer(2) * Float30::from_integer(3),
+                Float30::from_integer(6)
+            );
+            assert_eq!(
+                Float30::from_integer(2) * Float30::from_float(3.5),
+                Float30::from_float(7.0)
+            );
+        }
+
+        #[test]
+        fn div() {
+            assert_eq!(
+                Float30::from_integer(6) / Float30::from_integer(2),
+                Float30::from_integer(3)
+            );
+            assert_eq!(
+                Float30::from_integer(7) / Float30::from_integer(2),
+                Float30::from_float(3.5)
+            );
+            assert_eq!(
+                Float30::from_integer(1) / Float30::from_integer(0),
+                Float30::from_float(f32::INFINITY)
+            );
+            assert!((Float30::from_integer(0) / Float30::from_integer(0)).is_nan());
+        }
+
+        #[test]
+        fn rem() {
+            assert_eq!(
+                Float30::from_integer(5) % Float30::from_integer(2),
+                Float30::from_integer(1)
+            );
+            assert_eq!(
+                Float30::from_float(5.0) % Float30::from_float(2.0),
+                Float30::from_float(1.0)
+            );
+            assert!((Float30::from_integer(6) % Float30::from_integer(0)).is_nan());
+            assert_eq!(
+                Float30::from_integer(5).checked_rem(Float30::from_integer(0)),
+                None
+            );
+        }
+
+        #[test]
+        fn neg() {
+            assert_eq!(-Float30::from_integer(42), Float30::from_integer(-42));
+            assert_eq!(-Float30::from_float(4.2), Float30::from_float(-4.2));
+            assert!((-Float30::from_float(f32::NAN)).is_nan());
+        }
+
+        #[test]
+        fn keep_integer_within_range() {
+            assert_eq!(
+                Float30::from_integer(INTEGER_LIMIT - 2) + Float30::from_integer(1),
+                Float30::from_integer(INTEGER_LIMIT - 1)
+            );
+            assert_eq!(
+                Float30::from_integer(-INTEGER_LIMIT + 1) - Float30::from_integer(1),
+                Float30::from_integer(-INTEGER_LIMIT)
+            );
+        }
+
+        #[test]
+        fn saturate_to_infinity_on_float_overflow() {
+            assert_eq!(
+                (Float30::from_float(1e9) * Float30::from_float(1e9)).to_float(),
+                Some(f32::INFINITY)
+            );
+            assert_eq!(
+                (Float30::from_float(-1e9) * Float30::from_float(1e9)).to_float(),
+                Some(f32::NEG_INFINITY)
+            );
+        }
+
+        #[test]
+        fn cmp() {
+            assert_eq!(
+                Float30::from_integer(0).partial_cmp(&Float30::from_integer(1)),
+                Some(Ordering::Less)
+            );
+            assert_eq!(
+                Float30::from_integer(2).partial_cmp(&Float30::from_float(1.5)),
+                Some(Ordering::Greater)
+            );
+            assert_eq!(
+                Float30::from_float(f32::NAN).partial_cmp(&Float30::from_integer(0)),
+                None
+            );
+        }
+
+        #[test]
+        fn compare_integer_and_float_beyond_mantissa_precision() {
+            assert_ne!(
+                Float30::from_integer((1 << 24) + 1),
+                Float30::from_float((1 << 24) as f32)
+            );
+            assert_eq!(
+                Float30::from_integer((1 << 24) + 1)
+                    .partial_cmp(&Float30::from_float((1 << 24) as f32)),
+                Some(Ordering::Greater)
+            );
+            assert_eq!(
+                Float30::from_integer(INTEGER_LIMIT - 1)
+                    .partial_cmp(&Float30::from_float(INTEGER_LIMIT as f32)),
+                Some(Ordering::Less)
+            );
+        }
+
+        #[test]
+        fn equality() {
+            assert_eq!(Float30::from_integer(4), Float30::from_float(4.0));
+            assert_ne!(Float30::from_float(f32::NAN), Float30::from_float(f32::NAN));
+            assert_eq!(Float30::from_payload(42), Float30::from_payload(42));
+            assert_ne!(Float30::from_payload(4), Float30::from_integer(4));
+        }
+
+        #[test]
+        fn format() {
+            assert_eq!(Float30::from_integer(42).to_string(), "42");
+            assert_eq!(Float30::from_float(4.5).to_string(), "4.5");
+            assert_eq!(Float30::from_payload(42).to_string(), "0x2a");
+            assert_eq!(Float30::from_float(f32::INFINITY).to_string(), "inf");
+            assert_eq!(Float30::from_float(f32::NAN).to_string(), "NaN");
+        }
+    }
+}
@@ -1,11 +1,74 @@
 //! NaN boxing for 62-bit floating-pointer numbers encompassing 63-bit integers,
 //! 61-bit payloads, and infinities and NaN.
-
+//!
+//! # Memory layout
+//!
+//! [`Float62`] is stored in memory as its raw representation exclusive-ored
+//! with a reserved one in native endianness so that `Option<Float62>` has a
+//! niche. Casts with the `bytemuck` and `zerocopy` features read and write
+//! this in-memory format while [`Float62::to_le_bytes`] and its family convert
+//! raw representations. Since the in-memory format is native-endian, bytes
+//! cast from numbers are not portable across targets of different endianness.
+//!
+//! Casts from bytes reject the reserved raw representation stored as zero.
+//! Only ones with `bytemuck` also reject non-canonical representations while
+//! ones with `zerocopy` accept them as [`Float62::from_bits`] does.
+//!
+//! # Slice operations
+//!
+//! With the `simd` feature, functions on slices like [`add_slices`] use AVX2
+//! on x86-64 only if it is enabled at compile time with `target_feature`.
+//! They do not detect CPU features at run time. Integers are boxed, unboxed,
+//! added, subtracted, and multiplied in vectors while other numbers and
+//! integers out of range of vector operations are operated as scalars.
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(any(feature = "portable-atomic", target_has_atomic = "64"))]
+mod atomic;
+#[cfg(feature = "alloc")]
+mod big;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "decimal")]
+mod decimal;
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+mod edge;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(test)]
+mod random;
+#[cfg(feature = "rational")]
+mod rational;
+mod slice;
+mod transcode;
+#[cfg(feature = "alloc")]
+mod vec;
+
+use crate::nan_box::{Kind, NanBox};
+#[cfg(any(feature = "portable-atomic", target_has_atomic = "64"))]
+pub use atomic::AtomicFloat62;
+#[cfg(feature = "alloc")]
+pub use big::BigFloat62;
 use core::{
     cmp::Ordering,
-    fmt::Display,
+    error::Error,
+    fmt::{Debug, Display, Formatter},
+    hint::assert_unchecked,
+    num::{NonZeroU64, ParseFloatError},
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+    str::FromStr,
+};
+#[cfg(feature = "decimal")]
+pub use decimal::{Rounding, box_decimal, is_decimal, unbox_decimal, unbox_decimal_unchecked};
+#[cfg(feature = "rational")]
+pub use rational::{box_rational, is_rational, unbox_rational, unbox_rational_unchecked};
+pub use slice::{
+    add_slices, box_floats, box_integers, classify_mask, mul_slices, sub_slices, unbox_integers,
 };
+pub use transcode::{TranscodingError, from_f64_boxes, to_f64_boxes};
+#[cfg(feature = "alloc")]
+pub use vec::Float62Vec;
 
 const ROTATION_COUNT: u32 = 3;
 
@@ -14,10 +77,19 @@ const EXPONENT_MASK: u64 = (1 << 11) - 1;
 const MINIMUM_EXPONENT: u64 = 0x300;
 const MAXIMUM_EXPONENT: u64 = 0x4ff;
 
+const SIGN_MASK: u64 = 1 << 63;
+
 const SPECIAL_TAG: u64 = 0b101;
 const NAN: u64 = SPECIAL_TAG;
 const POSITIVE_INFINITY: u64 = (1 << 3) | SPECIAL_TAG;
 const NEGATIVE_INFINITY: u64 = (2 << 3) | SPECIAL_TAG;
+const NA: u64 = (3 << 3) | SPECIAL_TAG;
+// A reserved special value that is stored as zero so that `Option<Float62>` has
+// the same size as `Float62`.
+const NICHE: u64 = (0b11 << 62) | SPECIAL_TAG;
+
+const INTEGER_LIMIT: i64 = 1 << 62;
+const PAYLOAD_LIMIT: u64 = 1 << 61;
 
 /// Boxes a 63-bit signed integer.
 #[inline]
@@ -36,11 +108,33 @@ pub const fn unbox_integer(number: u64) -> Option<i64> {
 }
 
 /// Unboxes a 63-bit signed integer without any type check.
+///
+/// The type is asserted in debug builds with the `strict` feature.
 #[inline]
+#[cfg_attr(feature = "strict", track_caller)]
 pub const fn unbox_integer_unchecked(number: u64) -> i64 {
+    debug_assert!(!cfg!(feature = "strict") || is_integer(number));
+
     number as i64 >> 1
 }
 
+/// Unboxes a 63-bit signed integer assuming its type.
+///
+/// Unlike [`unbox_integer_unchecked`], the type is also passed to the
+/// optimizer so that type checks on the same number can be eliminated.
+///
+/// # Safety
+///
+/// The number must be an integer.
+#[inline]
+#[cfg_attr(feature = "strict", track_caller)]
+pub const unsafe fn unbox_integer_unchecked_hint(number: u64) -> i64 {
+    // SAFETY: The caller guarantees that the number is an integer.
+    unsafe { assert_unchecked(is_integer(number)) }
+
+    unbox_integer_unchecked(number)
+}
+
 /// Returns `true` if a number is an integer.
 #[inline]
 pub const fn is_integer(number: u64) -> bool {
@@ -64,70 +158,111 @@ pub const fn unbox_payload(number: u64) -> Option<u64> {
 }
 
 /// Unboxes a 61-bit payload without any type check.
+///
+/// The type is asserted in debug builds with the `strict` feature.
 #[inline]
+#[cfg_attr(feature = "strict", track_caller)]
 pub const fn unbox_payload_unchecked(number: u64) -> u64 {
+    debug_assert!(!cfg!(feature = "strict") || is_payload(number));
+
     number >> 3
 }
 
+/// Unboxes a 61-bit payload assuming its type.
+///
+/// # Safety
+///
+/// The number must be a payload.
+#[inline]
+#[cfg_attr(feature = "strict", track_caller)]
+pub const unsafe fn unbox_payload_unchecked_hint(number: u64) -> u64 {
+    // SAFETY: The caller guarantees that the number is a payload.
+    unsafe { assert_unchecked(is_payload(number)) }
+
+    unbox_payload_unchecked(number)
+}
+
 /// Returns `true` if a number is a payload.
 #[inline]
 pub const fn is_payload(number: u64) -> bool {
     number & 0b111 == 1
 }
 
+#[inline]
+const fn select(condition: bool, then: u64, otherwise: u64) -> u64 {
+    let mask = (condition as u64).wrapping_neg();
+
+    (then & mask) | (otherwise & !mask)
+}
+
 /// Boxes a 64-bit floating-point number.
+///
+/// Numbers too small in magnitude are flushed to zero and numbers too large in
+/// magnitude are saturated to infinities.
 #[inline]
 pub const fn box_float(number: f64) -> u64 {
-    if number == 0.0 {
-        0
-    } else if number.is_nan() {
-        NAN
-    } else if number == f64::INFINITY {
-        POSITIVE_INFINITY
-    } else if number == f64::NEG_INFINITY {
-        NEGATIVE_INFINITY
-    } else {
-        let bits = number.to_bits();
-        let exponent = bits >> MANTISSA_WIDTH & EXPONENT_MASK;
-
-        if exponent < MINIMUM_EXPONENT {
-            0
-        } else if exponent > MAXIMUM_EXPONENT {
-            if number < 0.0 {
-                NEGATIVE_INFINITY
-            } else {
-                POSITIVE_INFINITY
-            }
-        } else {
-            bits.rotate_left(ROTATION_COUNT) | 0b11
-        }
-    }
+    let bits = number.to_bits();
+    let exponent = bits >> MANTISSA_WIDTH & EXPONENT_MASK;
+    let special = select(
+        bits & !SIGN_MASK > f64::INFINITY.to_bits(),
+        NAN,
+        POSITIVE_INFINITY + ((bits >> 63) << 3),
+    );
+
+    select(
+        exponent > MAXIMUM_EXPONENT,
+        special,
+        select(
+            exponent < MINIMUM_EXPONENT,
+            0,
+            bits.rotate_left(ROTATION_COUNT) | 0b11,
+        ),
+    )
 }
 
 /// Unboxes a 64-bit floating-point number.
 #[inline]
 pub const fn unbox_float(number: u64) -> Option<f64> {
+    let index = number >> 3;
+
     if is_float(number) {
         Some(unbox_float_unchecked(number))
-    } else if is_nan(number) {
-        Some(f64::NAN)
-    } else if number == POSITIVE_INFINITY {
-        Some(f64::INFINITY)
-    } else if number == NEGATIVE_INFINITY {
-        Some(f64::NEG_INFINITY)
+    } else if number & 0b111 == SPECIAL_TAG && index < 3 {
+        Some(f64::from_bits(
+            f64::INFINITY.to_bits() | ((index == 0) as u64) << 51 | ((index == 2) as u64) << 63,
+        ))
     } else {
         None
     }
 }
 
 /// Unboxes a 64-bit floating-point number without any type check.
+///
+/// The type is asserted in debug builds with the `strict` feature.
 #[inline]
+#[cfg_attr(feature = "strict", track_caller)]
 pub const fn unbox_float_unchecked(number: u64) -> f64 {
+    debug_assert!(!cfg!(feature = "strict") || is_float(number));
+
     let exponent_tail = 2 - (number >> 63);
 
     f64::from_bits((number & !0b11 | exponent_tail).rotate_right(ROTATION_COUNT))
 }
 
+/// Unboxes a 64-bit floating-point number assuming its type.
+///
+/// # Safety
+///
+/// The number must be a 62-bit floating-point number.
+#[inline]
+#[cfg_attr(feature = "strict", track_caller)]
+pub const unsafe fn unbox_float_unchecked_hint(number: u64) -> f64 {
+    // SAFETY: The caller guarantees that the number is a floating-point number.
+    unsafe { assert_unchecked(is_float(number)) }
+
+    unbox_float_unchecked(number)
+}
+
 /// Returns `true` if a number is a 62-bit floating-point number.
 #[inline]
 pub const fn is_float(number: u64) -> bool {
@@ -146,22 +281,133 @@ pub const fn is_nan(number: u64) -> bool {
     number == NAN
 }
 
+/// Returns `true` if a number is a missing value.
+#[inline]
+pub const fn is_na(number: u64) -> bool {
+    number == NA
+}
+
+/// Returns `true` if a raw representation is canonical.
+///
+/// Integers, payloads, and finite floating-point numbers are always canonical.
+/// Among the other representations, only NaN, infinities, and a missing value
+/// are canonical. Rational and decimal numbers are also canonical if their
+/// features are enabled and they are in their lowest terms.
+#[inline]
+pub const fn is_canonical(number: u64) -> bool {
+    if number & 0b111 != SPECIAL_TAG {
+        return true;
+    }
+
+    #[cfg(feature = "rational")]
+    if let Some((numerator, denominator)) = unbox_rational(number) {
+        return Float62::from_rational(numerator, denominator).to_bits() == number;
+    }
+
+    #[cfg(feature = "decimal")]
+    if let Some((mantissa, scale)) = unbox_decimal(number) {
+        return Float62::from_decimal(mantissa, scale).to_bits() == number;
+    }
+
+    number >> 3 <= NA >> 3
+}
+
 /// A 62-bit floating-point number.
-#[derive(Clone, Copy, Debug, Default)]
+///
+/// `Option<Float62>` has the same size as `Float62`. Therefore, its in-memory
+/// representation differs from its raw representation as described in the
+/// [module documentation](self#memory-layout).
+#[derive(Clone, Copy)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::Immutable,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::TryFromBytes
+    )
+)]
 #[repr(transparent)]
-pub struct Float62(u64);
+pub struct Float62(NonZeroU64);
 
 impl Float62 {
+    /// A missing value distinct from NaN.
+    ///
+    /// It propagates through arithmetic operations with priority over NaN and
+    /// is not comparable with any numbers including itself.
+    pub const NA: Self = Self::from_bits(NA);
+
     /// Creates a 62-bit floating-point number from its raw representation.
+    ///
+    /// A reserved raw representation is converted into NaN. Use
+    /// [`try_from_bits`](Self::try_from_bits) for untrusted raw
+    /// representations instead.
     #[inline]
     pub const fn from_bits(number: u64) -> Self {
-        Self(number)
+        let number = number ^ NICHE;
+
+        // Map the reserved raw representation into NaN without branches.
+        // SAFETY: The stored representation of NaN is not zero.
+        Self(unsafe { NonZeroU64::new_unchecked(number | ((number == 0) as u64 * (NAN ^ NICHE))) })
+    }
+
+    /// Creates a 62-bit floating-point number from its raw representation,
+    /// rejecting non-canonical ones.
+    #[inline]
+    pub const fn try_from_bits(number: u64) -> Result<Self, RepresentationError> {
+        if is_canonical(number) {
+            Ok(Self::from_bits(number))
+        } else {
+            Err(RepresentationError)
+        }
+    }
+
+    #[inline]
+    const fn from_integer_bits(number: u64) -> Self {
+        debug_assert!(is_integer(number));
+
+        // SAFETY: The least significant bit of the reserved raw representation
+        // is one while those of integers are zero.
+        Self(unsafe { NonZeroU64::new_unchecked(number ^ NICHE) })
     }
 
     /// Returns a raw representation.
     #[inline]
     pub const fn to_bits(self) -> u64 {
-        self.0
+        self.0.get() ^ NICHE
+    }
+
+    /// Creates a 62-bit floating-point number from its raw representation in
+    /// little-endian bytes.
+    #[inline]
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        Self::from_bits(u64::from_le_bytes(bytes))
+    }
+
+    /// Creates a 62-bit floating-point number from its raw representation in
+    /// big-endian bytes.
+    #[inline]
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        Self::from_bits(u64::from_be_bytes(bytes))
+    }
+
+    /// Creates a 62-bit floating-point number from its raw representation in
+    /// little-endian bytes, rejecting non-canonical ones.
+    #[inline]
+    pub const fn try_from_bytes(bytes: [u8; 8]) -> Result<Self, RepresentationError> {
+        Self::try_from_bits(u64::from_le_bytes(bytes))
+    }
+
+    /// Returns a raw representation in little-endian bytes.
+    #[inline]
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        self.to_bits().to_le_bytes()
+    }
+
+    /// Returns a raw representation in big-endian bytes.
+    #[inline]
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        self.to_bits().to_be_bytes()
     }
 
     /// Creates a 62-bit floating-point number from a payload.
@@ -173,7 +419,7 @@ impl Float62 {
     /// Creates a 62-bit floating-point number from an integer.
     #[inline]
     pub const fn from_integer(integer: i64) -> Self {
-        Self::from_bits(box_integer(integer))
+        Self::from_integer_bits(box_integer(integer))
     }
 
     /// Creates a 62-bit floating-point number from a 64-bit floating-point
@@ -186,53 +432,306 @@ impl Float62 {
     /// Returns a payload.
     #[inline]
     pub const fn to_payload(self) -> Option<u64> {
-        unbox_payload(self.0)
+        unbox_payload(self.to_bits())
     }
 
     /// Returns a payload without any type check.
     #[inline]
+    #[cfg_attr(feature = "strict", track_caller)]
     pub const fn to_payload_unchecked(self) -> u64 {
-        unbox_payload_unchecked(self.0)
+        unbox_payload_unchecked(self.to_bits())
     }
 
     /// Returns an integer.
     #[inline]
     pub const fn to_integer(self) -> Option<i64> {
-        unbox_integer(self.0)
+        unbox_integer(self.to_bits())
     }
 
     /// Returns an integer without any type check.
     #[inline]
+    #[cfg_attr(feature = "strict", track_caller)]
     pub const fn to_integer_unchecked(self) -> i64 {
-        unbox_integer_unchecked(self.0)
+        unbox_integer_unchecked(self.to_bits())
     }
 
     /// Returns a 64-bit floating-point number.
     #[inline]
     pub const fn to_float(self) -> Option<f64> {
-        unbox_float(self.0)
+        unbox_float(self.to_bits())
     }
 
     /// Returns a 62-bit floating-point number without any type check.
     #[inline]
+    #[cfg_attr(feature = "strict", track_caller)]
     pub const fn to_float_unchecked(self) -> f64 {
-        unbox_float_unchecked(self.0)
+        unbox_float_unchecked(self.to_bits())
+    }
+
+    /// Returns a payload assuming its type.
+    ///
+    /// # Safety
+    ///
+    /// The number must be a payload.
+    #[inline]
+    #[cfg_attr(feature = "strict", track_caller)]
+    pub const unsafe fn to_payload_unchecked_hint(self) -> u64 {
+        // SAFETY: The caller upholds the same precondition.
+        unsafe { unbox_payload_unchecked_hint(self.to_bits()) }
+    }
+
+    /// Returns an integer assuming its type.
+    ///
+    /// # Safety
+    ///
+    /// The number must be an integer.
+    #[inline]
+    #[cfg_attr(feature = "strict", track_caller)]
+    pub const unsafe fn to_integer_unchecked_hint(self) -> i64 {
+        // SAFETY: The caller upholds the same precondition.
+        unsafe { unbox_integer_unchecked_hint(self.to_bits()) }
+    }
+
+    /// Returns a 62-bit floating-point number assuming its type.
+    ///
+    /// # Safety
+    ///
+    /// The number must be a 62-bit floating-point number.
+    #[inline]
+    #[cfg_attr(feature = "strict", track_caller)]
+    pub const unsafe fn to_float_unchecked_hint(self) -> f64 {
+        // SAFETY: The caller upholds the same precondition.
+        unsafe { unbox_float_unchecked_hint(self.to_bits()) }
+    }
+
+    /// Adds integers assuming their types and no overflow.
+    ///
+    /// # Safety
+    ///
+    /// Both numbers must be integers and their sum must be in the range of
+    /// 63-bit signed integers.
+    #[inline]
+    #[cfg_attr(feature = "strict", track_caller)]
+    pub const unsafe fn add_integers_unchecked(self, rhs: Self) -> Self {
+        let (x, y) = (self.to_bits(), rhs.to_bits());
+
+        debug_assert!(
+            !cfg!(feature = "strict")
+                || is_integer(x | y) && (x as i64).checked_add(y as _).is_some()
+        );
+
+        // SAFETY: The caller guarantees that both numbers are integers and
+        // their sum does not overflow.
+        unsafe {
+            assert_unchecked(is_integer(x | y));
+            Self::from_integer_bits((x as i64).unchecked_add(y as _) as _)
+        }
+    }
+
+    /// Subtracts integers assuming their types and no overflow.
+    ///
+    /// # Safety
+    ///
+    /// Both numbers must be integers and their difference must be in the range
+    /// of 63-bit signed integers.
+    #[inline]
+    #[cfg_attr(feature = "strict", track_caller)]
+    pub const unsafe fn sub_integers_unchecked(self, rhs: Self) -> Self {
+        let (x, y) = (self.to_bits(), rhs.to_bits());
+
+        debug_assert!(
+            !cfg!(feature = "strict")
+                || is_integer(x | y) && (x as i64).checked_sub(y as _).is_some()
+        );
+
+        // SAFETY: The caller guarantees that both numbers are integers and
+        // their difference does not overflow.
+        unsafe {
+            assert_unchecked(is_integer(x | y));
+            Self::from_integer_bits((x as i64).unchecked_sub(y as _) as _)
+        }
+    }
+
+    /// Multiplies integers assuming their types and no overflow.
+    ///
+    /// # Safety
+    ///
+    /// Both numbers must be integers and their product must be in the range of
+    /// 63-bit signed integers.
+    #[inline]
+    #[cfg_attr(feature = "strict", track_caller)]
+    pub const unsafe fn mul_integers_unchecked(self, rhs: Self) -> Self {
+        let (x, y) = (self.to_bits(), rhs.to_bits());
+
+        debug_assert!(
+            !cfg!(feature = "strict")
+                || is_integer(x | y) && (x as i64).checked_mul(y as i64 >> 1).is_some()
+        );
+
+        // SAFETY: The caller guarantees that both numbers are integers and
+        // their product does not overflow.
+        unsafe {
+            assert_unchecked(is_integer(x | y));
+            Self::from_integer_bits((x as i64).unchecked_mul(y as i64 >> 1) as _)
+        }
+    }
+
+    /// Returns `true` if this number has a canonical raw representation.
+    #[inline]
+    pub const fn is_canonical(self) -> bool {
+        is_canonical(self.to_bits())
+    }
+
+    /// Converts this number into one with a canonical raw representation.
+    ///
+    /// Rational and decimal numbers are reduced to their lowest terms and any
+    /// other non-canonical numbers are converted into NaN.
+    #[inline]
+    pub const fn canonicalize(self) -> Self {
+        if self.is_canonical() {
+            return self;
+        }
+
+        #[cfg(feature = "rational")]
+        if let Some((numerator, denominator)) = self.to_rational() {
+            return Self::from_rational(numerator, denominator);
+        }
+
+        #[cfg(feature = "decimal")]
+        if let Some((mantissa, scale)) = self.to_decimal() {
+            return Self::from_decimal(mantissa, scale);
+        }
+
+        Self::from_bits(NAN)
     }
 
     /// Returns `true` if this number is an infinity.
     #[inline]
     pub const fn is_infinite(self) -> bool {
-        is_infinite(self.0)
+        is_infinite(self.to_bits())
     }
 
     /// Returns `true` if this number is NaN.
     #[inline]
     pub const fn is_nan(self) -> bool {
-        is_nan(self.0)
+        is_nan(self.to_bits())
+    }
+
+    /// Returns `true` if this number is a missing value.
+    #[inline]
+    pub const fn is_na(self) -> bool {
+        is_na(self.to_bits())
+    }
+
+    /// Returns `true` if this number is an integer.
+    #[inline]
+    pub const fn is_integer(self) -> bool {
+        is_integer(self.to_bits())
+    }
+
+    /// Returns `true` if this number is a finite non-integer floating-point
+    /// number.
+    #[inline]
+    pub const fn is_float(self) -> bool {
+        is_float(self.to_bits())
+    }
+
+    /// Returns `true` if this number is a payload.
+    #[inline]
+    pub const fn is_payload(self) -> bool {
+        is_payload(self.to_bits())
+    }
+
+    /// Returns `true` if this number is neither a payload nor a missing value.
+    #[inline]
+    pub const fn is_number(self) -> bool {
+        !self.is_payload() && !self.is_na()
+    }
+
+    /// Returns `true` if this number is neither an infinity, NaN, nor a
+    /// payload.
+    #[inline]
+    pub const fn is_finite(self) -> bool {
+        #[cfg(feature = "rational")]
+        if self.is_rational() {
+            return true;
+        }
+
+        #[cfg(feature = "decimal")]
+        if self.is_decimal() {
+            return true;
+        }
+
+        self.is_integer() || self.is_float()
+    }
+
+    /// Decodes this number.
+    #[inline]
+    pub const fn classify(self) -> Float62Kind {
+        #[cfg(feature = "rational")]
+        if let Some((numerator, denominator)) = self.to_rational() {
+            return Float62Kind::Rational {
+                numerator,
+                denominator,
+            };
+        }
+
+        #[cfg(feature = "decimal")]
+        if let Some((mantissa, scale)) = self.to_decimal() {
+            return Float62Kind::Decimal { mantissa, scale };
+        }
+
+        if is_integer(self.to_bits()) {
+            Float62Kind::Integer(self.to_integer_unchecked())
+        } else if is_float(self.to_bits()) {
+            Float62Kind::Float(self.to_float_unchecked())
+        } else if is_payload(self.to_bits()) {
+            Float62Kind::Payload(self.to_payload_unchecked())
+        } else if self.to_bits() == POSITIVE_INFINITY {
+            Float62Kind::Infinity { negative: false }
+        } else if self.to_bits() == NEGATIVE_INFINITY {
+            Float62Kind::Infinity { negative: true }
+        } else if self.is_na() {
+            Float62Kind::Na
+        } else {
+            Float62Kind::Nan
+        }
+    }
+
+    /// Returns a tag.
+    #[inline]
+    pub const fn tag(self) -> Float62Tag {
+        if self.is_integer() {
+            Float62Tag::Integer
+        } else if self.is_float() {
+            Float62Tag::Float
+        } else if self.is_payload() {
+            Float62Tag::Payload
+        } else {
+            Float62Tag::Special
+        }
+    }
+
+    /// Returns `true` if this number has a tag.
+    #[inline]
+    pub const fn has_tag(self, tag: Float62Tag) -> bool {
+        let (mask, value) = tag.mask();
+
+        self.to_bits() & mask == value
     }
 
     #[inline]
     const fn to_number(self) -> Result<i64, f64> {
+        #[cfg(feature = "rational")]
+        if let Some((numerator, denominator)) = self.to_rational() {
+            return Err(numerator as f64 / denominator as f64);
+        }
+
+        #[cfg(feature = "decimal")]
+        if let Some(number) = self.to_decimal_float() {
+            return Err(number);
+        }
+
         if let Some(integer) = self.to_integer() {
             Ok(integer)
         } else if let Some(float) = self.to_float() {
@@ -243,31 +742,331 @@ impl Float62 {
     }
 }
 
-fn operate_float(lhs: Float62, rhs: Float62, operate: fn(f64, f64) -> f64) -> Float62 {
-    Float62::from_float(match (lhs.to_number(), rhs.to_number()) {
+impl Default for Float62 {
+    #[inline]
+    fn default() -> Self {
+        Self::from_integer(0)
+    }
+}
+
+impl Debug for Float62 {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter
+            .debug_tuple("Float62")
+            .field(&self.to_bits())
+            .finish()
+    }
+}
+
+/// A decoded 62-bit floating-point number.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Float62Kind {
+    /// An integer.
+    Integer(i64),
+    /// A finite non-integer floating-point number.
+    Float(f64),
+    /// A payload.
+    Payload(u64),
+    /// A non-integer rational number in its lowest terms.
+    #[cfg(feature = "rational")]
+    Rational {
+        /// A numerator.
+        numerator: i64,
+        /// A denominator greater than 1.
+        denominator: i64,
+    },
+    /// A non-integer decimal number of `mantissa * 10^-scale`.
+    #[cfg(feature = "decimal")]
+    Decimal {
+        /// A mantissa not divisible by 10.
+        mantissa: i64,
+        /// A scale greater than 0.
+        scale: u32,
+    },
+    /// NaN.
+    Nan,
+    /// A missing value.
+    Na,
+    /// An infinity.
+    Infinity {
+        /// Whether the infinity is negative.
+        negative: bool,
+    },
+}
+
+/// A tag of a raw representation of a 62-bit floating-point number.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Float62Tag {
+    /// An integer.
+    Integer,
+    /// A finite non-integer floating-point number.
+    Float,
+    /// A payload.
+    Payload,
+    /// NaN, an infinity, a missing value, or any other special value.
+    Special,
+}
+
+impl Float62Tag {
+    #[inline]
+    const fn mask(self) -> (u64, u64) {
+        match self {
+            Self::Integer => (0b1, 0),
+            Self::Float => (0b11, 0b11),
+            Self::Payload => (0b111, 1),
+            Self::Special => (0b111, SPECIAL_TAG),
+        }
+    }
+}
+
+impl From<Float62Kind> for Float62 {
+    #[inline]
+    fn from(kind: Float62Kind) -> Self {
+        match kind {
+            Float62Kind::Integer(integer) => Self::from_integer(integer),
+            Float62Kind::Float(number) => Self::from_float(number),
+            Float62Kind::Payload(payload) => Self::from_payload(payload),
+            #[cfg(feature = "rational")]
+            Float62Kind::Rational {
+                numerator,
+                denominator,
+            } => Self::from_rational(numerator, denominator),
+            #[cfg(feature = "decimal")]
+            Float62Kind::Decimal { mantissa, scale } => Self::from_decimal(mantissa, scale),
+            Float62Kind::Nan => Self::from_bits(NAN),
+            Float62Kind::Na => Self::NA,
+            Float62Kind::Infinity { negative: false } => Self::from_bits(POSITIVE_INFINITY),
+            Float62Kind::Infinity { negative: true } => Self::from_bits(NEGATIVE_INFINITY),
+        }
+    }
+}
+
+// Returns operands of a floating-point operation or `None` if either of them is
+// NA.
+#[inline]
+const fn float_operands(lhs: Float62, rhs: Float62) -> Option<(f64, f64)> {
+    debug_assert!(!cfg!(feature = "strict") || !is_integer(lhs.to_bits() | rhs.to_bits()));
+
+    if lhs.is_na() || rhs.is_na() {
+        return None;
+    }
+
+    Some(match (lhs.to_number(), rhs.to_number()) {
         (Ok(_), Ok(_)) => unreachable!(),
-        (Ok(x), Err(y)) => operate(x as f64, y),
-        (Err(x), Ok(y)) => operate(x, y as f64),
-        (Err(x), Err(y)) => operate(x, y),
+        (Ok(x), Err(y)) => (x as f64, y),
+        (Err(x), Ok(y)) => (x, y as f64),
+        (Err(x), Err(y)) => (x, y),
     })
 }
 
+macro_rules! operate_float {
+    ($name:ident, $operator:tt) => {
+        const fn $name(lhs: Float62, rhs: Float62) -> Float62 {
+            match float_operands(lhs, rhs) {
+                Some((x, y)) => Float62::from_float(x $operator y),
+                None => Float62::NA,
+            }
+        }
+    };
+}
+
+operate_float!(add_float, +);
+operate_float!(sub_float, -);
+operate_float!(mul_float, *);
+operate_float!(div_float, /);
+operate_float!(rem_float, %);
+
+// Integers are operated on their raw representations directly as the tags of
+// integers are zero. The right-hand side of multiplication is unboxed first.
 macro_rules! operate {
-    ($lhs:ident, $rhs:ident, $operate:ident, $wrapping_operate:ident) => {{
-        let (Some(x), Some(y)) = ($lhs.to_integer(), $rhs.to_integer()) else {
-            return operate_float($lhs, $rhs, f64::$operate);
-        };
+    (
+        $lhs:ident,
+        $rhs:ident,
+        $operate:ident,
+        $checked_operate:ident,
+        $operate_float:ident,
+        $operator:tt,
+        $shift:literal
+    ) => {{
+        let (x, y) = ($lhs.to_bits(), $rhs.to_bits());
+
+        if !is_integer(x | y) {
+            #[cfg(feature = "rational")]
+            if let Some(z) = rational::$operate($lhs, $rhs) {
+                return z;
+            }
+
+            #[cfg(feature = "decimal")]
+            if let Some(z) = decimal::$operate($lhs, $rhs) {
+                return z;
+            }
+
+            return $operate_float($lhs, $rhs);
+        }
 
-        Self::from_integer(x.$wrapping_operate(y))
+        match (x as i64).$checked_operate(y as i64 >> $shift) {
+            Some(z) => Self::from_integer_bits(z as _),
+            None => Self::from_float(
+                (unbox_integer_unchecked(x) as i128 $operator unbox_integer_unchecked(y) as i128)
+                    as _,
+            ),
+        }
     }};
 }
 
+impl Float62 {
+    /// Adds two numbers.
+    ///
+    /// It is the same as [`Add`] but can be evaluated in constant contexts.
+    #[inline]
+    pub const fn const_add(self, rhs: Self) -> Self {
+        operate!(self, rhs, add, checked_add, add_float, +, 0)
+    }
+
+    /// Subtracts two numbers.
+    ///
+    /// It is the same as [`Sub`] but can be evaluated in constant contexts.
+    #[inline]
+    pub const fn const_sub(self, rhs: Self) -> Self {
+        operate!(self, rhs, sub, checked_sub, sub_float, -, 0)
+    }
+
+    /// Multiplies two numbers.
+    ///
+    /// It is the same as [`Mul`] but can be evaluated in constant contexts.
+    #[inline]
+    pub const fn const_mul(self, rhs: Self) -> Self {
+        operate!(self, rhs, mul, checked_mul, mul_float, *, 1)
+    }
+
+    /// Divides two numbers.
+    ///
+    /// It is the same as [`Div`] but can be evaluated in constant contexts.
+    #[inline]
+    pub const fn const_div(self, rhs: Self) -> Self {
+        let (Some(x), Some(y)) = (self.to_integer(), rhs.to_integer()) else {
+            #[cfg(feature = "rational")]
+            if let Some(z) = rational::div(self, rhs) {
+                return z;
+            }
+
+            #[cfg(feature = "decimal")]
+            if let Some(z) = decimal::div(self, rhs) {
+                return z;
+            }
+
+            return div_float(self, rhs);
+        };
+
+        if y != 0 && x % y == 0 {
+            Self::from_integer(x / y)
+        } else {
+            #[cfg(feature = "rational")]
+            if y != 0 {
+                return Self::from_rational(x, y);
+            }
+
+            Self::from_float(x as f64 / y as f64)
+        }
+    }
+
+    /// Calculates the remainder of dividing this number by another number.
+    ///
+    /// It is the same as [`Rem`] but can be evaluated in constant contexts.
+    #[inline]
+    pub const fn const_rem(self, rhs: Self) -> Self {
+        let (Some(x), Some(y)) = (self.to_integer(), rhs.to_integer()) else {
+            return rem_float(self, rhs);
+        };
+
+        if y == 0 {
+            Self::from_float(f64::NAN)
+        } else {
+            Self::from_integer(x % y)
+        }
+    }
+
+    /// Negates this number.
+    ///
+    /// It is the same as [`Neg`] but can be evaluated in constant contexts.
+    #[inline]
+    pub const fn const_neg(self) -> Self {
+        let x = self.to_bits();
+
+        if is_integer(x) {
+            return match (x as i64).checked_neg() {
+                Some(z) => Self::from_integer_bits(z as _),
+                None => Self::from_float(-(unbox_integer_unchecked(x) as f64)),
+            };
+        } else if self.is_na() {
+            return self;
+        }
+
+        #[cfg(feature = "rational")]
+        if let Some((x, y)) = self.to_rational() {
+            return Self::from_rational(-x, y);
+        }
+
+        #[cfg(feature = "decimal")]
+        if let Some((mantissa, scale)) = self.to_decimal() {
+            return Self::from_decimal(-mantissa, scale);
+        }
+
+        match self.to_number() {
+            Ok(x) => Self::from_integer(-x),
+            Err(x) => Self::from_float(-x),
+        }
+    }
+
+    /// Compares two numbers.
+    ///
+    /// It is the same as [`PartialOrd`] but can be evaluated in constant
+    /// contexts.
+    #[inline]
+    pub const fn const_cmp(self, rhs: Self) -> Option<Ordering> {
+        let (x, y) = (self.to_bits(), rhs.to_bits());
+
+        if is_integer(x | y) {
+            return Some(compare_integers(x as i64 as _, y as i64 as _));
+        } else if self.is_na() || rhs.is_na() {
+            return None;
+        } else if x == y {
+            return if self.is_nan() {
+                None
+            } else {
+                Some(Ordering::Equal)
+            };
+        }
+
+        #[cfg(feature = "rational")]
+        if let (Some((x, y)), Some((z, w))) = (self.to_fraction(), rhs.to_fraction()) {
+            return Some(compare_integers(x * w, z * y));
+        }
+
+        #[cfg(feature = "decimal")]
+        if let Some(ordering) = decimal::compare(self, rhs) {
+            return Some(ordering);
+        }
+
+        match (self.to_number(), rhs.to_number()) {
+            (Ok(x), Ok(y)) => Some(compare_integers(x as _, y as _)),
+            (Ok(x), Err(y)) => compare_integer_and_float(x, y),
+            (Err(x), Ok(y)) => match compare_integer_and_float(y, x) {
+                Some(ordering) => Some(ordering.reverse()),
+                None => None,
+            },
+            (Err(x), Err(y)) => compare_floats(x, y),
+        }
+    }
+}
+
 impl Add for Float62 {
     type Output = Self;
 
     #[inline]
     fn add(self, rhs: Self) -> Self::Output {
-        operate!(self, rhs, add, wrapping_add)
+        self.const_add(rhs)
     }
 }
 
@@ -276,7 +1075,7 @@ impl Sub for Float62 {
 
     #[inline]
     fn sub(self, rhs: Self) -> Self::Output {
-        operate!(self, rhs, sub, wrapping_sub)
+        self.const_sub(rhs)
     }
 }
 
@@ -285,7 +1084,7 @@ impl Mul for Float62 {
 
     #[inline]
     fn mul(self, rhs: Self) -> Self::Output {
-        operate!(self, rhs, mul, wrapping_mul)
+        self.const_mul(rhs)
     }
 }
 
@@ -294,15 +1093,7 @@ impl Div for Float62 {
 
     #[inline]
     fn div(self, rhs: Self) -> Self::Output {
-        let (Some(x), Some(y)) = (self.to_integer(), rhs.to_integer()) else {
-            return operate_float(self, rhs, f64::div);
-        };
-
-        if y != 0 && x % y == 0 {
-            Self::from_integer(x / y)
-        } else {
-            Self::from_float(x as f64 / y as f64)
-        }
+        self.const_div(rhs)
     }
 }
 
@@ -311,15 +1102,7 @@ impl Rem for Float62 {
 
     #[inline]
     fn rem(self, rhs: Self) -> Self::Output {
-        let (Some(x), Some(y)) = (self.to_integer(), rhs.to_integer()) else {
-            return operate_float(self, rhs, f64::rem);
-        };
-
-        if y == 0 {
-            Self::from_float(f64::NAN)
-        } else {
-            Self::from_integer(x % y)
-        }
+        self.const_rem(rhs)
     }
 }
 
@@ -335,8 +1118,86 @@ impl Float62 {
 
         Some(Self::from_integer(x.checked_rem(y)?))
     }
+
+    #[inline]
+    const fn check_operands(self, rhs: Self) -> Result<(), OperandError> {
+        if self.is_payload() || rhs.is_payload() {
+            Err(OperandError)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Adds two numbers, returning an error if either of them is a payload.
+    #[inline]
+    pub fn try_add(self, rhs: Self) -> Result<Self, OperandError> {
+        self.check_operands(rhs)?;
+
+        Ok(self + rhs)
+    }
+
+    /// Subtracts two numbers, returning an error if either of them is a
+    /// payload.
+    #[inline]
+    pub fn try_sub(self, rhs: Self) -> Result<Self, OperandError> {
+        self.check_operands(rhs)?;
+
+        Ok(self - rhs)
+    }
+
+    /// Multiplies two numbers, returning an error if either of them is a
+    /// payload.
+    #[inline]
+    pub fn try_mul(self, rhs: Self) -> Result<Self, OperandError> {
+        self.check_operands(rhs)?;
+
+        Ok(self * rhs)
+    }
+
+    /// Divides two numbers, returning an error if either of them is a payload.
+    #[inline]
+    pub fn try_div(self, rhs: Self) -> Result<Self, OperandError> {
+        self.check_operands(rhs)?;
+
+        Ok(self / rhs)
+    }
+
+    /// Compares two numbers, returning an error if either of them is a
+    /// payload.
+    #[inline]
+    pub fn try_cmp(self, rhs: Self) -> Result<Option<Ordering>, OperandError> {
+        self.check_operands(rhs)?;
+
+        Ok(self.partial_cmp(&rhs))
+    }
+}
+
+/// An error of arithmetic operations on payload operands.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct OperandError;
+
+impl Display for OperandError {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(formatter, "payload operand")
+    }
+}
+
+impl Error for OperandError {}
+
+/// An error of non-canonical raw representations.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RepresentationError;
+
+impl Display for RepresentationError {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(formatter, "non-canonical representation")
+    }
 }
 
+impl Error for RepresentationError {}
+
 impl AddAssign for Float62 {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
@@ -370,32 +1231,121 @@ impl Neg for Float62 {
 
     #[inline]
     fn neg(self) -> Self::Output {
-        match self.to_number() {
-            Ok(x) => Self::from_integer(-x),
-            Err(x) => Self::from_float(-x),
+        self.const_neg()
+    }
+}
+
+impl Display for Float62 {
+    #[inline]
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        #[cfg(feature = "rational")]
+        if let Some((numerator, denominator)) = self.to_rational() {
+            return write!(formatter, "{numerator}/{denominator}");
+        }
+
+        #[cfg(feature = "decimal")]
+        if let Some(result) = self.fmt_decimal(formatter) {
+            return result;
+        }
+
+        if let Some(integer) = self.to_integer() {
+            write!(formatter, "{integer}")
+        } else if let Some(float) = self.to_float() {
+            write!(formatter, "{float}")
+        } else if self.is_na() {
+            write!(formatter, "NA")
+        } else if is_payload(self.to_bits()) {
+            write!(formatter, "0x{:x}", self.to_payload_unchecked())
+        } else {
+            // Non-canonical special values are NaN.
+            write!(formatter, "NaN")
+        }
+    }
+}
+
+impl FromStr for Float62 {
+    type Err = ParseFloatError;
+
+    /// Parses a number.
+    ///
+    /// A string of digits with an optional sign is parsed into an integer if
+    /// it fits in 63 bits. With the `decimal` feature, one with a decimal point
+    /// is parsed into a decimal number as well. `NA` is parsed into NA and `0x`
+    /// followed by hexadecimal digits into a payload of at most 61 bits. With
+    /// the `rational` feature, two integers separated by `/` are parsed into a
+    /// rational number. Any other string is parsed as a floating-point number.
+    /// So, strings which [`Display`] writes for numbers other than
+    /// floating-point ones are parsed back into the same numbers.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        #[cfg(feature = "decimal")]
+        if let Some(number) = decimal::parse(string) {
+            return Ok(number);
+        }
+
+        parse(string).map_or_else(|| string.parse().map(Self::from_float), Ok)
+    }
+}
+
+// Parses strings of numbers other than floating-point numbers.
+fn parse(string: &str) -> Option<Float62> {
+    if let Ok(integer) = string.parse::<i64>() {
+        return (-INTEGER_LIMIT..INTEGER_LIMIT)
+            .contains(&integer)
+            .then(|| Float62::from_integer(integer));
+    } else if string == "NA" {
+        return Some(Float62::NA);
+    } else if let Some(digits) = string.strip_prefix("0x") {
+        // Reject signs which `u64::from_str_radix` accepts.
+        if !digits.starts_with(|digit: char| digit.is_ascii_hexdigit()) {
+            return None;
         }
+
+        return u64::from_str_radix(digits, 16)
+            .ok()
+            .filter(|payload| *payload < PAYLOAD_LIMIT)
+            .map(Float62::from_payload);
+    }
+
+    #[cfg(feature = "rational")]
+    if let Some((numerator, denominator)) = string.split_once('/') {
+        return Some(Float62::from_rational(
+            numerator.parse().ok()?,
+            denominator.parse().ok()?,
+        ));
     }
+
+    None
 }
 
-impl Display for Float62 {
-    #[inline]
-    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if let Some(integer) = self.to_integer() {
-            write!(formatter, "{integer}")
-        } else if let Some(float) = self.to_float() {
-            write!(formatter, "{float}")
-        } else {
-            write!(formatter, "0x{:x}", self.to_payload_unchecked())
-        }
+const fn compare_integers(x: i128, y: i128) -> Ordering {
+    if x < y {
+        Ordering::Less
+    } else if x > y {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+const fn compare_floats(x: f64, y: f64) -> Option<Ordering> {
+    if x < y {
+        Some(Ordering::Less)
+    } else if x > y {
+        Some(Ordering::Greater)
+    } else if x == y {
+        Some(Ordering::Equal)
+    } else {
+        None
     }
 }
 
-fn compare_integer_and_float(integer: i64, float: f64) -> Option<Ordering> {
+const fn compare_integer_and_float(integer: i64, float: f64) -> Option<Ordering> {
     let rounded = integer as f64;
 
-    match rounded.partial_cmp(&float)? {
-        Ordering::Equal => integer.partial_cmp(&(rounded as i64)),
-        ordering => Some(ordering),
+    match compare_floats(rounded, float) {
+        Some(Ordering::Equal) => Some(compare_integers(integer as _, rounded as i64 as _)),
+        ordering => ordering,
     }
 }
 
@@ -409,25 +1359,54 @@ impl PartialEq for Float62 {
 impl PartialOrd for Float62 {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self.0 == other.0 {
-            return (!self.is_nan()).then_some(Ordering::Equal);
-        }
+        self.const_cmp(*other)
+    }
+}
 
-        match (self.to_number(), other.to_number()) {
-            (Ok(x), Ok(y)) => x.partial_cmp(&y),
-            (Ok(x), Err(y)) => compare_integer_and_float(x, y),
-            (Err(x), Ok(y)) => compare_integer_and_float(y, x).map(Ordering::reverse),
-            (Err(x), Err(y)) => x.partial_cmp(&y),
+impl NanBox for Float62 {
+    const PAYLOAD_WIDTH: u32 = 61;
+    const INTEGER_MIN: i64 = -(1 << 62);
+    const INTEGER_MAX: i64 = (1 << 62) - 1;
+
+    #[inline]
+    fn from_integer(integer: i64) -> Self {
+        Self::from_integer(integer)
+    }
+
+    #[inline]
+    fn from_float(number: f64) -> Self {
+        Self::from_float(number)
+    }
+
+    #[inline]
+    fn from_payload(payload: u64) -> Self {
+        Self::from_payload(payload)
+    }
+
+    #[inline]
+    fn classify(self) -> Kind {
+        match self.classify() {
+            Float62Kind::Integer(integer) => Kind::Integer(integer),
+            Float62Kind::Float(number) => Kind::Float(number),
+            Float62Kind::Payload(payload) => Kind::Payload(payload),
+            #[cfg(feature = "rational")]
+            Float62Kind::Rational {
+                numerator,
+                denominator,
+            } => Kind::Float(numerator as f64 / denominator as f64),
+            #[cfg(feature = "decimal")]
+            Float62Kind::Decimal { .. } => Kind::Float(self.to_decimal_float().unwrap_or(f64::NAN)),
+            Float62Kind::Nan | Float62Kind::Na => Kind::Float(f64::NAN),
+            Float62Kind::Infinity { negative: false } => Kind::Float(f64::INFINITY),
+            Float62Kind::Infinity { negative: true } => Kind::Float(f64::NEG_INFINITY),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-    use alloc::string::ToString;
-
-    const INTEGER_LIMIT: i64 = 1 << 62;
+    use super::{random::random_bits, *};
+    use alloc::{string::ToString, vec};
 
     #[test]
     fn integer() {
@@ -537,6 +1516,204 @@ mod tests {
         assert!(!is_float(number));
     }
 
+    #[test]
+    fn na() {
+        assert!(is_na(NA));
+        assert!(!is_nan(NA));
+        assert!(!is_infinite(NA));
+        assert!(!is_integer(NA));
+        assert!(!is_payload(NA));
+        assert!(!is_float(NA));
+        assert_eq!(unbox_float(NA), None);
+        assert!(!is_na(box_float(f64::NAN)));
+    }
+
+    fn box_float_reference(number: f64) -> u64 {
+        let exponent = number.to_bits() >> MANTISSA_WIDTH & EXPONENT_MASK;
+
+        if number.is_nan() {
+            NAN
+        } else if exponent < MINIMUM_EXPONENT {
+            0
+        } else if exponent > MAXIMUM_EXPONENT {
+            if number < 0.0 {
+                NEGATIVE_INFINITY
+            } else {
+                POSITIVE_INFINITY
+            }
+        } else {
+            number.to_bits().rotate_left(ROTATION_COUNT) | 0b11
+        }
+    }
+
+    fn unbox_float_reference(number: u64) -> Option<f64> {
+        if is_float(number) {
+            Some(unbox_float_unchecked(number))
+        } else if number == NAN {
+            Some(f64::NAN)
+        } else if number == POSITIVE_INFINITY {
+            Some(f64::INFINITY)
+        } else if number == NEGATIVE_INFINITY {
+            Some(f64::NEG_INFINITY)
+        } else {
+            None
+        }
+    }
+
+    #[test]
+    fn box_float_matches_reference() {
+        let mut numbers = vec![
+            0.0,
+            -0.0,
+            f64::NAN,
+            -f64::NAN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::MIN_POSITIVE,
+            f64::MAX,
+            f64::MIN,
+            f64::EPSILON,
+            f64::from_bits(1),
+            f64::from_bits(0x7ff0_0000_0000_0001),
+            f64::from_bits(0xfff8_0000_0000_0001),
+        ];
+
+        for exponent in [
+            MINIMUM_EXPONENT - 1,
+            MINIMUM_EXPONENT,
+            MAXIMUM_EXPONENT,
+            MAXIMUM_EXPONENT + 1,
+        ] {
+            for mantissa in [0, 1, (1 << MANTISSA_WIDTH) - 1] {
+                for sign in [0, SIGN_MASK] {
+                    numbers.push(f64::from_bits(sign | exponent << MANTISSA_WIDTH | mantissa));
+                }
+            }
+        }
+
+        numbers.extend(random_bits().map(f64::from_bits));
+
+        for number in numbers {
+            assert_eq!(box_float(number), box_float_reference(number), "{number}");
+        }
+    }
+
+    #[test]
+    fn unbox_float_matches_reference() {
+        for number in (0..8)
+            .map(|index| index << 3 | SPECIAL_TAG)
+            .chain(random_bits())
+        {
+            assert_eq!(
+                unbox_float(number).map(f64::to_bits),
+                unbox_float_reference(number).map(f64::to_bits),
+                "{number:x}"
+            );
+        }
+    }
+
+    #[cfg(all(feature = "strict", debug_assertions))]
+    mod strict {
+        use super::*;
+
+        #[test]
+        #[should_panic]
+        fn assert_integer() {
+            unbox_integer_unchecked(box_float(4.2));
+        }
+
+        #[test]
+        #[should_panic]
+        fn assert_payload() {
+            Float62::from_integer(42).to_payload_unchecked();
+        }
+
+        #[test]
+        #[should_panic]
+        fn assert_float() {
+            Float62::from_float(f64::NAN).to_float_unchecked();
+        }
+
+        #[test]
+        #[should_panic]
+        fn assert_float_operands() {
+            float_operands(Float62::from_integer(1), Float62::from_integer(2));
+        }
+
+        #[test]
+        fn format_specials() {
+            for subtag in 0..4 {
+                for index in 0..8 {
+                    let number = Float62::from_bits(subtag << 62 | index << 3 | SPECIAL_TAG);
+
+                    assert!(!number.to_string().is_empty());
+                }
+            }
+
+            for index in 4..8 {
+                assert_eq!(
+                    Float62::from_bits(index << 3 | SPECIAL_TAG).to_string(),
+                    "NaN"
+                );
+            }
+        }
+
+        #[test]
+        #[should_panic]
+        fn assert_integer_operands() {
+            // SAFETY: The assertion panics before any undefined behavior.
+            unsafe { Float62::from_float(4.2).add_integers_unchecked(Float62::from_integer(1)) };
+        }
+
+        #[test]
+        #[should_panic]
+        fn assert_no_overflow() {
+            let number = Float62::from_integer((1 << 62) - 1);
+
+            // SAFETY: The assertion panics before any undefined behavior.
+            unsafe { number.mul_integers_unchecked(number) };
+        }
+    }
+
+    #[test]
+    fn canonical_floats() {
+        for number in random_bits().filter(|number| is_float(*number)) {
+            assert!(is_canonical(number));
+            assert_eq!(box_float(unbox_float_unchecked(number)), number);
+        }
+    }
+
+    #[test]
+    fn canonical_specials() {
+        for number in [NAN, POSITIVE_INFINITY, NEGATIVE_INFINITY, NA] {
+            assert!(is_canonical(number));
+        }
+
+        for number in [(4 << 3) | SPECIAL_TAG, (1 << 40) | NAN, NICHE, !0b10] {
+            assert!(!is_canonical(number));
+        }
+    }
+
+    #[test]
+    fn canonicalize() {
+        for number in random_bits().chain([NICHE, (4 << 3) | SPECIAL_TAG]) {
+            let canonical = Float62::from_bits(number).canonicalize();
+
+            assert!(canonical.is_canonical(), "{number:x}");
+            assert_eq!(
+                Float62::try_from_bits(canonical.to_bits()).map(Float62::to_bits),
+                Ok(canonical.to_bits())
+            );
+
+            if is_canonical(number) {
+                assert_eq!(canonical.to_bits(), number);
+                assert!(Float62::try_from_bits(number).is_ok());
+            } else {
+                assert_eq!(Float62::try_from_bits(number), Err(RepresentationError));
+            }
+        }
+    }
+
     #[test]
     fn distinguish_representations() {
         let classify = |number| {
@@ -581,6 +1758,111 @@ mod tests {
             assert_eq!(Float62::default(), Float62::from_float(0.0));
         }
 
+        #[test]
+        fn option_size() {
+            assert_eq!(size_of::<Option<Float62>>(), size_of::<Float62>());
+            assert_eq!(size_of::<Option<Float62>>(), size_of::<u64>());
+        }
+
+        #[test]
+        fn reserved_bits() {
+            assert_eq!(Float62::from_bits(NICHE).to_bits(), NAN);
+
+            for number in [
+                box_integer(0),
+                box_integer(-1),
+                box_float(4.2),
+                box_float(f64::NAN),
+                box_float(f64::INFINITY),
+                box_payload(0),
+                box_payload(u64::MAX),
+                NA,
+                u64::MAX,
+            ] {
+                assert_eq!(Float62::from_bits(number).to_bits(), number);
+            }
+        }
+
+        #[test]
+        fn bytes() {
+            for number in [
+                Float62::from_integer(-42),
+                Float62::from_float(4.2),
+                Float62::from_payload(42),
+                Float62::from_float(f64::NEG_INFINITY),
+                Float62::NA,
+            ] {
+                assert_eq!(
+                    Float62::from_le_bytes(number.to_le_bytes()).to_bits(),
+                    number.to_bits()
+                );
+                assert_eq!(
+                    Float62::from_be_bytes(number.to_be_bytes()).to_bits(),
+                    number.to_bits()
+                );
+                assert_eq!(
+                    Float62::try_from_bytes(number.to_le_bytes()).map(Float62::to_bits),
+                    Ok(number.to_bits())
+                );
+            }
+
+            assert_eq!(
+                Float62::from_integer(1).to_le_bytes(),
+                [2, 0, 0, 0, 0, 0, 0, 0]
+            );
+            assert_eq!(
+                Float62::from_integer(1).to_be_bytes(),
+                [0, 0, 0, 0, 0, 0, 0, 2]
+            );
+        }
+
+        #[test]
+        fn reject_non_canonical_bytes() {
+            for number in [(4 << 3) | SPECIAL_TAG, (1 << 40) | NAN, NICHE, !0b10] {
+                assert_eq!(
+                    Float62::try_from_bytes(number.to_le_bytes()).map(Float62::to_bits),
+                    Err(RepresentationError)
+                );
+            }
+        }
+
+        #[cfg(feature = "zerocopy")]
+        #[test]
+        fn zerocopy() {
+            use zerocopy::{IntoBytes, TryFromBytes};
+
+            let number = Float62::from_integer(1);
+
+            assert_eq!(number.as_bytes(), (number.to_bits() ^ NICHE).to_ne_bytes());
+            assert_ne!(number.as_bytes(), number.to_le_bytes());
+
+            let numbers = [
+                Float62::from_integer(-42),
+                Float62::from_float(4.2),
+                Float62::from_payload(42),
+                Float62::NA,
+            ];
+
+            assert_eq!(
+                <[Float62]>::try_ref_from_bytes(numbers.as_bytes())
+                    .unwrap()
+                    .iter()
+                    .map(|number| number.to_bits())
+                    .collect::<alloc::vec::Vec<_>>(),
+                numbers.map(Float62::to_bits)
+            );
+            assert!(Float62::try_read_from_bytes(&[0; 8]).is_err());
+            assert!(<[Float62]>::try_ref_from_bytes([0u64; 2].as_bytes()).is_err());
+        }
+
+        #[test]
+        fn debug() {
+            assert_eq!(
+                alloc::format!("{:?}", Float62::from_integer(1)),
+                "Float62(2)"
+            );
+        }
+
         #[test]
         fn negative_zero() {
             assert_eq!(Float62::from_float(-0.0), Float62::from_integer(0));
@@ -744,6 +2026,99 @@ mod tests {
             assert!((-Float62::from_float(f64::NAN)).is_nan());
         }
 
+        #[test]
+        fn na() {
+            assert!(Float62::NA.is_na());
+            assert!(!Float62::NA.is_nan());
+            assert!(!Float62::NA.is_number());
+            assert!(!Float62::NA.is_finite());
+            assert_eq!(Float62::NA.to_float(), None);
+            assert_eq!(Float62::NA.to_integer(), None);
+            assert_eq!(Float62::NA.to_payload(), None);
+            assert!(!Float62::from_float(f64::NAN).is_na());
+        }
+
+        #[test]
+        fn propagate_na() {
+            let nan = Float62::from_float(f64::NAN);
+
+            for x in [
+                Float62::from_integer(42),
+                Float62::from_float(4.2),
+                Float62::from_float(f64::INFINITY),
+                Float62::from_payload(42),
+                nan,
+                Float62::NA,
+            ] {
+                for (y, z) in [(x, Float62::NA), (Float62::NA, x)] {
+                    assert!((y + z).is_na());
+                    assert!((y - z).is_na());
+                    assert!((y * z).is_na());
+                    assert!((y / z).is_na());
+                    assert!((y % z).is_na());
+                    assert!(y.checked_rem(z).unwrap().is_na());
+                }
+            }
+
+            assert!((-Float62::NA).is_na());
+            assert!((Float62::NA / Float62::from_integer(0)).is_na());
+            assert!((nan + Float62::NA).is_na());
+            assert!((Float62::NA + nan).is_na());
+        }
+
+        #[test]
+        fn try_operate() {
+            let x = Float62::from_integer(7);
+            let y = Float62::from_float(0.5);
+
+            assert_eq!(x.try_add(y), Ok(Float62::from_float(7.5)));
+            assert_eq!(x.try_sub(y), Ok(Float62::from_float(6.5)));
+            assert_eq!(x.try_mul(y), Ok(Float62::from_float(3.5)));
+            assert_eq!(x.try_div(y), Ok(Float62::from_integer(14)));
+            assert_eq!(x.try_cmp(y), Ok(Some(Ordering::Greater)));
+            assert_eq!(Float62::from_float(f64::NAN).try_cmp(x), Ok(None));
+            assert!(Float62::NA.try_add(x).unwrap().is_na());
+        }
+
+        #[test]
+        fn reject_payload_operands() {
+            let payload = Float62::from_payload(42);
+
+            for x in [
+                Float62::from_integer(42),
+                Float62::from_float(4.2),
+                Float62::from_float(f64::NAN),
+                Float62::NA,
+                payload,
+            ] {
+                for (y, z) in [(x, payload), (payload, x)] {
+                    assert_eq!(y.try_add(z), Err(OperandError));
+                    assert_eq!(y.try_sub(z), Err(OperandError));
+                    assert_eq!(y.try_mul(z), Err(OperandError));
+                    assert_eq!(y.try_div(z), Err(OperandError));
+                    assert_eq!(y.try_cmp(z), Err(OperandError));
+                }
+            }
+
+            assert_eq!(OperandError.to_string(), "payload operand");
+        }
+
+        #[test]
+        fn compare_na() {
+            for x in [
+                Float62::from_integer(42),
+                Float62::from_float(4.2),
+                Float62::from_float(f64::INFINITY),
+                Float62::from_float(f64::NAN),
+                Float62::NA,
+            ] {
+                assert_eq!(x.partial_cmp(&Float62::NA), None);
+                assert_eq!(Float62::NA.partial_cmp(&x), None);
+                assert_ne!(x, Float62::NA);
+                assert_ne!(Float62::NA, x);
+            }
+        }
+
         #[test]
         fn rem() {
             assert_eq!(
@@ -866,47 +2241,138 @@ mod tests {
                 -INTEGER_LIMIT + 1,
             ];
 
+            let reference = |z: i128| {
+                if (-INTEGER_LIMIT as i128..INTEGER_LIMIT as i128).contains(&z) {
+                    Float62::from_integer(z as _)
+                } else {
+                    Float62::from_float(z as _)
+                }
+            };
+
+            for &x in &values {
+                for &y in &values {
+                    let (z, w) = (Float62::from_integer(x), Float62::from_integer(y));
+                    let (x, y) = (i128::from(x), i128::from(y));
+
+                    assert_eq!((z + w).to_bits(), reference(x + y).to_bits());
+                    assert_eq!((z - w).to_bits(), reference(x - y).to_bits());
+                    assert_eq!((z * w).to_bits(), reference(x * y).to_bits());
+                    assert_eq!(z.partial_cmp(&w), x.partial_cmp(&y));
+                }
+
+                assert_eq!(
+                    (-Float62::from_integer(x)).to_bits(),
+                    reference(-i128::from(x)).to_bits()
+                );
+            }
+        }
+
+        #[test]
+        fn const_operate() {
+            const ONE: Float62 = Float62::from_integer(1);
+            const HALF: Float62 = Float62::from_float(0.5);
+            const NUMBERS: [Float62; 7] = [
+                ONE.const_add(ONE),
+                ONE.const_sub(HALF),
+                HALF.const_mul(HALF),
+                ONE.const_div(Float62::from_integer(4)),
+                Float62::from_integer(7).const_rem(Float62::from_integer(3)),
+                HALF.const_neg(),
+                Float62::from_integer(INTEGER_LIMIT - 1).const_add(ONE),
+            ];
+            const ORDERINGS: [Option<Ordering>; 4] = [
+                ONE.const_cmp(HALF),
+                HALF.const_cmp(ONE),
+                HALF.const_cmp(HALF),
+                Float62::NA.const_cmp(ONE),
+            ];
+
+            assert_eq!(
+                NUMBERS,
+                [
+                    Float62::from_integer(2),
+                    Float62::from_float(0.5),
+                    Float62::from_float(0.25),
+                    Float62::from_float(0.25),
+                    Float62::from_integer(1),
+                    Float62::from_float(-0.5),
+                    Float62::from_float(INTEGER_LIMIT as f64),
+                ]
+            );
+            assert_eq!(
+                ORDERINGS,
+                [
+                    Some(Ordering::Greater),
+                    Some(Ordering::Less),
+                    Some(Ordering::Equal),
+                    None
+                ]
+            );
+        }
+
+        #[test]
+        fn operate_integers_unchecked() {
+            let values = [0, 1, -1, 42, -42, 1 << 30, -(1 << 30)];
+
             for &x in &values {
                 for &y in &values {
-                    assert_eq!(
-                        (Float62::from_integer(x) + Float62::from_integer(y)).to_bits(),
-                        Float62::from_integer(x.wrapping_add(y)).to_bits()
-                    );
-                    assert_eq!(
-                        (Float62::from_integer(x) - Float62::from_integer(y)).to_bits(),
-                        Float62::from_integer(x.wrapping_sub(y)).to_bits()
-                    );
-                    assert_eq!(
-                        (Float62::from_integer(x) * Float62::from_integer(y)).to_bits(),
-                        Float62::from_integer(x.wrapping_mul(y)).to_bits()
-                    );
+                    let (x, y) = (Float62::from_integer(x), Float62::from_integer(y));
+
+                    // SAFETY: The numbers are integers and the results are in
+                    // range.
+                    unsafe {
+                        assert_eq!(x.add_integers_unchecked(y).to_bits(), (x + y).to_bits());
+                        assert_eq!(x.sub_integers_unchecked(y).to_bits(), (x - y).to_bits());
+                        assert_eq!(x.mul_integers_unchecked(y).to_bits(), (x * y).to_bits());
+                    }
                 }
             }
         }
 
         #[test]
-        fn arithmetic_out_of_range_integers() {
+        fn unbox_unchecked_hint() {
+            let (integer, float, payload) = (
+                Float62::from_integer(-42),
+                Float62::from_float(4.2),
+                Float62::from_payload(42),
+            );
+
+            // SAFETY: The numbers have the assumed types.
+            unsafe {
+                assert_eq!(integer.to_integer_unchecked_hint(), -42);
+                assert_eq!(float.to_float_unchecked_hint(), 4.2);
+                assert_eq!(payload.to_payload_unchecked_hint(), 42);
+            }
+        }
+
+        #[test]
+        fn promote_out_of_range_integers() {
             let big = Float62::from_integer(1 << 60);
             let huge = Float62::from_integer(INTEGER_LIMIT - 1);
 
             assert_eq!(big.to_integer(), Some(1 << 60));
+            assert_eq!((big + big).to_integer(), Some(1 << 61));
             assert_eq!(
-                (big + big).to_bits(),
-                Float62::from_integer((1i64 << 60).wrapping_add(1 << 60)).to_bits()
+                (big + big + big + big).to_float(),
+                Some((1u64 << 62) as f64)
             );
             assert_eq!((big - big).to_bits(), Float62::from_integer(0).to_bits());
+            assert_eq!((big * big).to_float(), Some((1u128 << 120) as f64));
             assert_eq!(
-                (big * big).to_bits(),
-                Float62::from_integer((1i64 << 60).wrapping_mul(1 << 60)).to_bits()
+                (huge + huge).to_float(),
+                Some(((INTEGER_LIMIT - 1) * 2) as f64)
             );
             assert_eq!(
-                (huge + huge).to_bits(),
-                Float62::from_integer((INTEGER_LIMIT - 1) * 2).to_bits()
+                (-huge - huge - Float62::from_integer(2)).to_float(),
+                Some(-(INTEGER_LIMIT as f64) * 2.0)
             );
             assert_eq!(
-                (huge * huge).to_bits(),
-                Float62::from_integer((INTEGER_LIMIT - 1).wrapping_mul(INTEGER_LIMIT - 1))
-                    .to_bits()
+                (huge * huge).to_float(),
+                Some((i128::from(INTEGER_LIMIT - 1) * i128::from(INTEGER_LIMIT - 1)) as f64)
+            );
+            assert_eq!(
+                (-Float62::from_integer(-INTEGER_LIMIT)).to_float(),
+                Some(INTEGER_LIMIT as f64)
             );
         }
 
@@ -1101,6 +2567,75 @@ mod tests {
             }
         }
 
+        #[test]
+        fn classify() {
+            assert_eq!(
+                Float62::from_integer(42).classify(),
+                Float62Kind::Integer(42)
+            );
+            assert_eq!(Float62::from_float(0.0).classify(), Float62Kind::Integer(0));
+            assert_eq!(Float62::from_float(4.2).classify(), Float62Kind::Float(4.2));
+            assert_eq!(
+                Float62::from_payload(42).classify(),
+                Float62Kind::Payload(42)
+            );
+            assert_eq!(Float62::from_float(f64::NAN).classify(), Float62Kind::Nan);
+            assert_eq!(
+                Float62::from_float(f64::INFINITY).classify(),
+                Float62Kind::Infinity { negative: false }
+            );
+            assert_eq!(
+                Float62::from_float(f64::NEG_INFINITY).classify(),
+                Float62Kind::Infinity { negative: true }
+            );
+            assert_eq!(
+                Float62::from_bits((4 << 3) | SPECIAL_TAG).classify(),
+                Float62Kind::Nan
+            );
+        }
+
+        #[test]
+        fn convert_from_kind() {
+            for number in [
+                Float62::from_integer(42),
+                Float62::from_float(4.2),
+                Float62::from_payload(42),
+                Float62::from_float(f64::NAN),
+                Float62::from_float(f64::INFINITY),
+                Float62::from_float(f64::NEG_INFINITY),
+                Float62::NA,
+            ] {
+                assert_eq!(Float62::from(number.classify()).to_bits(), number.to_bits());
+            }
+        }
+
+        #[test]
+        fn predicates() {
+            for number in [
+                Float62::from_integer(42),
+                Float62::from_float(4.2),
+                Float62::from_payload(42),
+                Float62::from_float(f64::NAN),
+                Float62::from_float(f64::INFINITY),
+                Float62::from_float(f64::NEG_INFINITY),
+                Float62::NA,
+            ] {
+                let kind = number.classify();
+
+                assert_eq!(number.is_integer(), matches!(kind, Float62Kind::Integer(_)));
+                assert_eq!(number.is_float(), matches!(kind, Float62Kind::Float(_)));
+                assert_eq!(number.is_payload(), matches!(kind, Float62Kind::Payload(_)));
+                assert_eq!(number.is_nan(), kind == Float62Kind::Nan);
+                assert_eq!(number.is_na(), kind == Float62Kind::Na);
+                assert_eq!(
+                    number.is_infinite(),
+                    matches!(kind, Float62Kind::Infinity { .. })
+                );
+                assert_eq!(number.is_number(), !number.is_payload() && !number.is_na());
+                assert_eq!(number.is_finite(), number.is_integer() || number.is_float());
+            }
+        }
+
         #[test]
         fn format() {
             assert_eq!(Float62::from_integer(0).to_string(), "0");
@@ -1117,6 +2652,57 @@ mod tests {
             assert_eq!(Float62::from_float(f64::INFINITY).to_string(), "inf");
             assert_eq!(Float62::from_float(f64::NEG_INFINITY).to_string(), "-inf");
             assert_eq!(Float62::from_float(f64::NAN).to_string(), "NaN");
+            assert_eq!(Float62::NA.to_string(), "NA");
+        }
+
+        fn parse(string: &str) -> Float62 {
+            string.parse().unwrap()
+        }
+
+        #[test]
+        fn parse_string() {
+            assert_eq!(parse("42").to_integer(), Some(42));
+            assert_eq!(parse("-42").to_integer(), Some(-42));
+            assert_eq!(parse("+42").to_integer(), Some(42));
+            assert_eq!(parse("1e3").to_float(), Some(1e3));
+            assert_eq!(parse("inf").to_float(), Some(f64::INFINITY));
+            assert!(parse("NaN").is_nan());
+            assert_eq!(
+                parse("4611686018427387904").to_float(),
+                Some(INTEGER_LIMIT as f64)
+            );
+            assert!("".parse::<Float62>().is_err());
+            assert!("foo".parse::<Float62>().is_err());
+        }
+
+        #[test]
+        fn parse_special_string() {
+            assert!(parse("NA").is_na());
+            assert_eq!(parse("0x2a").to_payload(), Some(42));
+            assert_eq!(
+                parse("0x1fffffffffffffff").to_payload(),
+                Some((1 << 61) - 1)
+            );
+            assert!("0x2000000000000000".parse::<Float62>().is_err());
+            assert!("0x+2a".parse::<Float62>().is_err());
+            assert!("0x".parse::<Float62>().is_err());
+            assert!("na".parse::<Float62>().is_err());
+        }
+
+        #[test]
+        fn format_and_parse() {
+            for number in [
+                Float62::from_integer(-42),
+                Float62::from_integer(INTEGER_LIMIT - 1),
+                Float62::from_float(f64::NEG_INFINITY),
+                Float62::NA,
+                Float62::from_payload(42),
+            ] {
+                assert_eq!(
+                    number.to_string().parse::<Float62>().unwrap().to_bits(),
+                    number.to_bits()
+                );
+            }
         }
     }
 }
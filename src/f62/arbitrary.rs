@@ -0,0 +1,64 @@
+use super::{
+    Float62,
+    edge::{EDGE_CASES, INTEGER_MAXIMUM, INTEGER_MINIMUM},
+};
+use ::arbitrary::{Arbitrary, Result, Unstructured, size_hint};
+
+impl<'a> Arbitrary<'a> for Float62 {
+    #[inline]
+    fn arbitrary(unstructured: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match unstructured.int_in_range(0..=3)? {
+            0 => *unstructured.choose(&EDGE_CASES)?,
+            1 => Self::from_integer(unstructured.int_in_range(INTEGER_MINIMUM..=INTEGER_MAXIMUM)?),
+            2 => Self::from_float(f64::arbitrary(unstructured)?),
+            _ => Self::from_bits(u64::arbitrary(unstructured)?),
+        })
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(u8::size_hint(depth), u64::size_hint(depth))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f62::random::random_bits;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn generate_edge_cases() {
+        let data = random_bits().flat_map(u64::to_le_bytes).collect::<Vec<_>>();
+        let mut unstructured = Unstructured::new(&data);
+        let mut numbers = Vec::new();
+
+        while let Ok(number) = Float62::arbitrary(&mut unstructured) {
+            if unstructured.is_empty() {
+                break;
+            }
+
+            numbers.push(number);
+        }
+
+        for edge_case in EDGE_CASES {
+            assert!(
+                numbers
+                    .iter()
+                    .any(|number| number.to_bits() == edge_case.to_bits())
+            );
+        }
+
+        assert!(numbers.iter().any(|number| number.is_integer()));
+        assert!(numbers.iter().any(|number| number.is_float()));
+        assert!(numbers.iter().any(|number| number.is_payload()));
+    }
+
+    #[test]
+    fn generate_from_empty_data() {
+        assert_eq!(
+            Float62::arbitrary(&mut Unstructured::new(&[])).map(Float62::to_bits),
+            Ok(Float62::from_integer(0).to_bits())
+        );
+    }
+}
@@ -0,0 +1,402 @@
+use super::{Float62, is_integer};
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::AtomicU64;
+use core::{
+    cmp,
+    fmt::{self, Debug, Formatter},
+    sync::atomic::Ordering,
+};
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::AtomicU64;
+
+/// A 62-bit floating-point number which can be safely shared between threads.
+///
+/// Arithmetic and comparison operations are implemented with compare-and-swap
+/// loops.
+#[repr(transparent)]
+pub struct AtomicFloat62(AtomicU64);
+
+impl AtomicFloat62 {
+    /// Creates an atomic number.
+    #[inline]
+    pub const fn new(number: Float62) -> Self {
+        Self(AtomicU64::new(number.to_bits()))
+    }
+
+    /// Consumes the atomic number and returns the contained number.
+    #[inline]
+    pub const fn into_inner(self) -> Float62 {
+        Float62::from_bits(self.0.into_inner())
+    }
+
+    /// Loads a number.
+    #[inline]
+    pub fn load(&self, ordering: Ordering) -> Float62 {
+        Float62::from_bits(self.0.load(ordering))
+    }
+
+    /// Stores a number.
+    #[inline]
+    pub fn store(&self, number: Float62, ordering: Ordering) {
+        self.0.store(number.to_bits(), ordering)
+    }
+
+    /// Stores a number and returns the previous one.
+    #[inline]
+    pub fn swap(&self, number: Float62, ordering: Ordering) -> Float62 {
+        Float62::from_bits(self.0.swap(number.to_bits(), ordering))
+    }
+
+    /// Stores a new number if the current one is the same as an expected one.
+    ///
+    /// Numbers are compared by their raw representations rather than by
+    /// [`PartialEq`] so that NaN can be exchanged as well.
+    #[inline]
+    pub fn compare_exchange(
+        &self,
+        current: Float62,
+        new: Float62,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Float62, Float62> {
+        self.0
+            .compare_exchange(current.to_bits(), new.to_bits(), success, failure)
+            .map(Float62::from_bits)
+            .map_err(Float62::from_bits)
+    }
+
+    /// Stores a new number if the current one is the same as an expected one.
+    ///
+    /// Unlike [`compare_exchange`](Self::compare_exchange), this function can
+    /// fail spuriously.
+    #[inline]
+    pub fn compare_exchange_weak(
+        &self,
+        current: Float62,
+        new: Float62,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Float62, Float62> {
+        self.0
+            .compare_exchange_weak(current.to_bits(), new.to_bits(), success, failure)
+            .map(Float62::from_bits)
+            .map_err(Float62::from_bits)
+    }
+
+    /// Updates a number with a function and returns the previous one.
+    ///
+    /// The function can be called multiple times if the number is updated
+    /// concurrently. It returns `Err` with the current number if the
+    /// function returns `None`.
+    #[inline]
+    pub fn fetch_update(
+        &self,
+        set_ordering: Ordering,
+        fetch_ordering: Ordering,
+        mut update: impl FnMut(Float62) -> Option<Float62>,
+    ) -> Result<Float62, Float62> {
+        self.0
+            .fetch_update(set_ordering, fetch_ordering, |number| {
+                update(Float62::from_bits(number)).map(Float62::to_bits)
+            })
+            .map(Float62::from_bits)
+            .map_err(Float62::from_bits)
+    }
+
+    #[inline]
+    fn fetch_operate(&self, ordering: Ordering, operate: impl Fn(Float62) -> Float62) -> Float62 {
+        match self.fetch_update(ordering, fetch_ordering(ordering), |number| {
+            Some(operate(number))
+        }) {
+            Ok(number) | Err(number) => number,
+        }
+    }
+
+    /// Adds a number and returns the previous one.
+    ///
+    /// If both numbers are integers, their raw representations are added
+    /// directly in a compare-and-swap loop. Otherwise, or if the sum
+    /// overflows, it falls back to [`Add`](core::ops::Add) and integers are
+    /// promoted to floating-point numbers.
+    #[inline]
+    pub fn fetch_add(&self, number: Float62, ordering: Ordering) -> Float62 {
+        let y = number.to_bits();
+
+        if is_integer(y) {
+            let mut x = self.0.load(Ordering::Relaxed);
+
+            while is_integer(x)
+                && let Some(z) = (x as i64).checked_add(y as _)
+            {
+                match self
+                    .0
+                    .compare_exchange_weak(x, z as _, ordering, fetch_ordering(ordering))
+                {
+                    Ok(x) => return Float62::from_bits(x),
+                    Err(current) => x = current,
+                }
+            }
+        }
+
+        self.fetch_operate(ordering, |current| current + number)
+    }
+
+    /// Subtracts a number and returns the previous one.
+    #[inline]
+    pub fn fetch_sub(&self, number: Float62, ordering: Ordering) -> Float62 {
+        self.fetch_operate(ordering, |current| current - number)
+    }
+
+    /// Stores the maximum of the current number and a given one and returns
+    /// the previous one.
+    ///
+    /// NaN is replaced by any other number while other incomparable numbers
+    /// are kept as they are.
+    #[inline]
+    pub fn fetch_max(&self, number: Float62, ordering: Ordering) -> Float62 {
+        self.fetch_operate(ordering, |current| {
+            select(current, number, cmp::Ordering::Less)
+        })
+    }
+
+    /// Stores the minimum of the current number and a given one and returns
+    /// the previous one.
+    ///
+    /// NaN is replaced by any other number while other incomparable numbers
+    /// are kept as they are.
+    #[inline]
+    pub fn fetch_min(&self, number: Float62, ordering: Ordering) -> Float62 {
+        self.fetch_operate(ordering, |current| {
+            select(current, number, cmp::Ordering::Greater)
+        })
+    }
+}
+
+const fn fetch_ordering(ordering: Ordering) -> Ordering {
+    match ordering {
+        Ordering::Release | Ordering::Relaxed => Ordering::Relaxed,
+        Ordering::SeqCst => Ordering::SeqCst,
+        _ => Ordering::Acquire,
+    }
+}
+
+fn select(current: Float62, number: Float62, ordering: cmp::Ordering) -> Float62 {
+    match current.partial_cmp(&number) {
+        Some(actual) if actual == ordering => number,
+        None if current.is_nan() => number,
+        _ => current,
+    }
+}
+
+impl Default for AtomicFloat62 {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Float62::default())
+    }
+}
+
+impl From<Float62> for AtomicFloat62 {
+    #[inline]
+    fn from(number: Float62) -> Self {
+        Self::new(number)
+    }
+}
+
+impl Debug for AtomicFloat62 {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.load(Ordering::Relaxed), formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    const THREAD_COUNT: usize = 8;
+    const ITERATION_COUNT: usize = 1000;
+
+    #[test]
+    fn load_and_store() {
+        let number = AtomicFloat62::new(Float62::from_integer(42));
+
+        assert_eq!(number.load(Ordering::Relaxed), Float62::from_integer(42));
+
+        number.store(Float62::from_float(4.2), Ordering::Relaxed);
+
+        assert_eq!(number.load(Ordering::Relaxed), Float62::from_float(4.2));
+        assert_eq!(
+            number.swap(Float62::from_integer(1), Ordering::Relaxed),
+            Float62::from_float(4.2)
+        );
+        assert_eq!(number.into_inner(), Float62::from_integer(1));
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(AtomicFloat62::default().into_inner(), Float62::default());
+    }
+
+    #[test]
+    fn compare_exchange() {
+        let number = AtomicFloat62::new(Float62::from_float(f64::NAN));
+        let nan = Float62::from_float(f64::NAN);
+        let one = Float62::from_integer(1);
+
+        assert!(
+            number
+                .compare_exchange(one, one, Ordering::SeqCst, Ordering::SeqCst)
+                .is_err()
+        );
+        assert!(
+            number
+                .compare_exchange(nan, one, Ordering::SeqCst, Ordering::SeqCst)
+                .unwrap()
+                .is_nan()
+        );
+        assert_eq!(number.load(Ordering::SeqCst), one);
+    }
+
+    #[test]
+    fn fetch_update() {
+        let number = AtomicFloat62::new(Float62::from_integer(1));
+
+        assert_eq!(
+            number.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |_| None),
+            Err(Float62::from_integer(1))
+        );
+        assert_eq!(
+            number.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| Some(-x)),
+            Ok(Float62::from_integer(1))
+        );
+        assert_eq!(number.into_inner(), Float62::from_integer(-1));
+    }
+
+    #[test]
+    fn fetch_add() {
+        let number = AtomicFloat62::new(Float62::from_integer(1));
+
+        assert_eq!(
+            number.fetch_add(Float62::from_integer(2), Ordering::Relaxed),
+            Float62::from_integer(1)
+        );
+        assert_eq!(
+            number.fetch_add(Float62::from_float(0.5), Ordering::Release),
+            Float62::from_integer(3)
+        );
+        assert_eq!(
+            number.fetch_sub(Float62::from_float(1.5), Ordering::AcqRel),
+            Float62::from_float(3.5)
+        );
+        assert_eq!(number.into_inner(), Float62::from_integer(2));
+    }
+
+    #[test]
+    fn fetch_add_overflow() {
+        let number = AtomicFloat62::new(Float62::from_integer((1 << 62) - 1));
+
+        number.fetch_add(Float62::from_integer(1), Ordering::SeqCst);
+
+        assert_eq!(
+            number.into_inner(),
+            Float62::from_float((1u64 << 62) as f64)
+        );
+    }
+
+    #[test]
+    fn fetch_max_and_min() {
+        let number = AtomicFloat62::new(Float62::from_integer(1));
+
+        number.fetch_max(Float62::from_float(1.5), Ordering::SeqCst);
+        assert_eq!(number.load(Ordering::SeqCst), Float62::from_float(1.5));
+        number.fetch_max(Float62::from_integer(0), Ordering::SeqCst);
+        assert_eq!(number.load(Ordering::SeqCst), Float62::from_float(1.5));
+        number.fetch_min(Float62::from_integer(-1), Ordering::SeqCst);
+        assert_eq!(number.load(Ordering::SeqCst), Float62::from_integer(-1));
+        number.fetch_min(Float62::from_float(f64::NAN), Ordering::SeqCst);
+        assert_eq!(number.load(Ordering::SeqCst), Float62::from_integer(-1));
+
+        number.store(Float62::from_float(f64::NAN), Ordering::SeqCst);
+        number.fetch_min(Float62::from_integer(7), Ordering::SeqCst);
+        assert_eq!(number.load(Ordering::SeqCst), Float62::from_integer(7));
+
+        number.store(Float62::NA, Ordering::SeqCst);
+        number.fetch_max(Float62::from_integer(7), Ordering::SeqCst);
+        assert!(number.load(Ordering::SeqCst).is_na());
+    }
+
+    #[test]
+    fn add_concurrently() {
+        let number = AtomicFloat62::default();
+
+        thread::scope(|scope| {
+            for _ in 0..THREAD_COUNT {
+                scope.spawn(|| {
+                    for _ in 0..ITERATION_COUNT {
+                        number.fetch_add(Float62::from_integer(1), Ordering::Relaxed);
+                    }
+                });
+            }
+        });
+
+        assert_eq!(
+            number.into_inner(),
+            Float62::from_integer((THREAD_COUNT * ITERATION_COUNT) as _)
+        );
+    }
+
+    #[test]
+    fn add_integers_concurrently() {
+        let number = AtomicFloat62::new(Float62::from_integer(-(1 << 50)));
+
+        thread::scope(|scope| {
+            for thread in 0..THREAD_COUNT {
+                let number = &number;
+
+                scope.spawn(move || {
+                    for _ in 0..ITERATION_COUNT {
+                        number.fetch_add(
+                            Float62::from_integer(if thread % 2 == 0 {
+                                3 << 40
+                            } else {
+                                -(1 << 40)
+                            }),
+                            Ordering::AcqRel,
+                        );
+                    }
+                });
+            }
+        });
+
+        assert_eq!(
+            number.into_inner().to_integer(),
+            Some(-(1 << 50) + (THREAD_COUNT * ITERATION_COUNT) as i64 / 2 * (2 << 40))
+        );
+    }
+
+    #[test]
+    fn max_concurrently() {
+        let number = AtomicFloat62::new(Float62::from_integer(0));
+
+        thread::scope(|scope| {
+            for thread in 0..THREAD_COUNT {
+                let number = &number;
+
+                scope.spawn(move || {
+                    for index in 0..ITERATION_COUNT {
+                        number.fetch_max(
+                            Float62::from_integer((index * THREAD_COUNT + thread) as _),
+                            Ordering::Relaxed,
+                        );
+                    }
+                });
+            }
+        });
+
+        assert_eq!(
+            number.into_inner(),
+            Float62::from_integer((THREAD_COUNT * ITERATION_COUNT - 1) as _)
+        );
+    }
+}
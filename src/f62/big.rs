@@ -0,0 +1,522 @@
+//! Arbitrary-precision integers promoted from overflowing ones.
+//!
+//! An arbitrary-precision integer is allocated on heap and its pointer is
+//! boxed into a 61-bit payload. Allocations are aligned to 8 bytes so that the
+//! pointer fits after its 3 least significant bits, which are always zero,
+//! are dropped.
+
+use super::Float62;
+use alloc::boxed::Box;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, ToPrimitive, Zero};
+
+const INTEGER_MINIMUM: i64 = -(1 << 62);
+const INTEGER_MAXIMUM: i64 = (1 << 62) - 1;
+const ALIGNMENT_WIDTH: u32 = 3;
+
+// `BigInt` is aligned to only 4 bytes on 32-bit targets.
+#[repr(align(8))]
+struct AlignedBigInt(BigInt);
+
+const _: () = assert!(align_of::<AlignedBigInt>() == 1 << ALIGNMENT_WIDTH);
+
+/// A 62-bit floating-point number whose integers are promoted to
+/// arbitrary-precision integers on overflow.
+///
+/// Arbitrary-precision integers are allocated on heap and referenced by
+/// payloads. Therefore, this type does not have any other payloads.
+pub struct BigFloat62(Float62);
+
+enum Operand<'a> {
+    Integer(i64),
+    BigInteger(&'a BigInt),
+    Float(Float62),
+}
+
+impl Operand<'_> {
+    fn to_float62(&self) -> Float62 {
+        match self {
+            Self::Integer(integer) => Float62::from_integer(*integer),
+            Self::BigInteger(integer) => Float62::from_float(integer.to_f64().unwrap_or(f64::NAN)),
+            Self::Float(number) => *number,
+        }
+    }
+
+    fn to_big_integer(&self) -> Option<BigInt> {
+        match self {
+            Self::Integer(integer) => Some((*integer).into()),
+            Self::BigInteger(integer) => Some((*integer).clone()),
+            Self::Float(_) => None,
+        }
+    }
+}
+
+impl BigFloat62 {
+    /// Creates a number from an integer.
+    #[inline]
+    pub fn from_integer(integer: i64) -> Self {
+        if (INTEGER_MINIMUM..=INTEGER_MAXIMUM).contains(&integer) {
+            Self(Float62::from_integer(integer))
+        } else {
+            Self::allocate(integer.into())
+        }
+    }
+
+    /// Creates a number from an arbitrary-precision integer.
+    ///
+    /// An integer is stored inline if it fits in 63 bits.
+    #[inline]
+    pub fn from_big_integer(integer: BigInt) -> Self {
+        match integer.to_i64() {
+            Some(integer) if (INTEGER_MINIMUM..=INTEGER_MAXIMUM).contains(&integer) => {
+                Self(Float62::from_integer(integer))
+            }
+            _ => Self::allocate(integer),
+        }
+    }
+
+    /// Creates a number from a 64-bit floating-point number.
+    #[inline]
+    pub const fn from_float(number: f64) -> Self {
+        Self(Float62::from_float(number))
+    }
+
+    fn from_i128(integer: i128) -> Self {
+        i64::try_from(integer).map_or_else(|_| Self::allocate(integer.into()), Self::from_integer)
+    }
+
+    fn allocate(integer: BigInt) -> Self {
+        Self(Float62::from_payload(
+            Box::into_raw(Box::new(AlignedBigInt(integer))) as u64 >> ALIGNMENT_WIDTH,
+        ))
+    }
+
+    /// Returns an integer if it fits in 64 bits.
+    #[inline]
+    pub fn to_integer(&self) -> Option<i64> {
+        match self.operand() {
+            Operand::Integer(integer) => Some(integer),
+            Operand::BigInteger(integer) => integer.to_i64(),
+            Operand::Float(_) => None,
+        }
+    }
+
+    /// Returns an arbitrary-precision integer.
+    #[inline]
+    pub fn to_big_integer(&self) -> Option<BigInt> {
+        self.operand().to_big_integer()
+    }
+
+    /// Returns a 64-bit floating-point number.
+    #[inline]
+    pub fn to_float(&self) -> Option<f64> {
+        match self.operand() {
+            Operand::Float(number) => number.to_float(),
+            Operand::Integer(_) | Operand::BigInteger(_) => None,
+        }
+    }
+
+    /// Returns a 62-bit floating-point number if this number is not an
+    /// arbitrary-precision integer.
+    #[inline]
+    pub const fn to_float62(&self) -> Option<Float62> {
+        if self.is_big_integer() {
+            None
+        } else {
+            Some(self.0)
+        }
+    }
+
+    /// Returns `true` if this number is an integer.
+    #[inline]
+    pub const fn is_integer(&self) -> bool {
+        self.0.is_integer() || self.is_big_integer()
+    }
+
+    /// Returns `true` if this number is an arbitrary-precision integer
+    /// allocated on heap.
+    #[inline]
+    pub const fn is_big_integer(&self) -> bool {
+        self.0.is_payload()
+    }
+
+    fn big_integer(&self) -> Option<&BigInt> {
+        self.0.to_payload().map(|payload| {
+            // SAFETY: Payloads always point to live arbitrary-precision integers owned
+            // by this number.
+            unsafe { &(*((payload << ALIGNMENT_WIDTH) as *const AlignedBigInt)).0 }
+        })
+    }
+
+    fn operand(&self) -> Operand<'_> {
+        if self.0.is_integer() {
+            Operand::Integer(self.0.to_integer_unchecked())
+        } else {
+            self.big_integer()
+                .map_or(Operand::Float(self.0), Operand::BigInteger)
+        }
+    }
+}
+
+impl Clone for BigFloat62 {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.big_integer()
+            .map_or_else(|| Self(self.0), |integer| Self::allocate(integer.clone()))
+    }
+}
+
+impl Drop for BigFloat62 {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(payload) = self.0.to_payload() {
+            // SAFETY: Payloads are always created by `Box::into_raw` and owned by this
+            // number.
+            drop(unsafe { Box::from_raw((payload << ALIGNMENT_WIDTH) as *mut AlignedBigInt) });
+        }
+    }
+}
+
+impl Default for BigFloat62 {
+    #[inline]
+    fn default() -> Self {
+        Self::from_integer(0)
+    }
+}
+
+impl From<Float62> for BigFloat62 {
+    /// Converts a 62-bit floating-point number.
+    ///
+    /// A payload is converted into NaN.
+    #[inline]
+    fn from(number: Float62) -> Self {
+        if number.is_payload() {
+            Self::from_float(f64::NAN)
+        } else {
+            Self(number)
+        }
+    }
+}
+
+impl From<BigInt> for BigFloat62 {
+    #[inline]
+    fn from(integer: BigInt) -> Self {
+        Self::from_big_integer(integer)
+    }
+}
+
+macro_rules! operate {
+    ($trait:ident, $method:ident, $operate:ident) => {
+        impl $trait for &BigFloat62 {
+            type Output = BigFloat62;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$operate(rhs)
+            }
+        }
+
+        impl $trait for BigFloat62 {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                (&self).$operate(&rhs)
+            }
+        }
+    };
+}
+
+operate!(Add, add, add_ref);
+operate!(Sub, sub, sub_ref);
+operate!(Mul, mul, mul_ref);
+operate!(Div, div, div_ref);
+
+macro_rules! operate_integer {
+    ($name:ident, $operate:ident) => {
+        fn $name(&self, rhs: &Self) -> Self {
+            match (self.operand(), rhs.operand()) {
+                (Operand::Integer(x), Operand::Integer(y)) => {
+                    Self::from_i128((x as i128).$operate(y as i128))
+                }
+                (x @ Operand::Float(_), y) | (x, y @ Operand::Float(_)) => {
+                    Self(x.to_float62().$operate(y.to_float62()))
+                }
+                (x, y) => Self::from_big_integer(
+                    x.to_big_integer()
+                        .unwrap_or_default()
+                        .$operate(y.to_big_integer().unwrap_or_default()),
+                ),
+            }
+        }
+    };
+}
+
+impl BigFloat62 {
+    operate_integer!(add_ref, add);
+    operate_integer!(sub_ref, sub);
+    operate_integer!(mul_ref, mul);
+
+    fn div_ref(&self, rhs: &Self) -> Self {
+        let (Some(x), Some(y)) = (self.to_big_integer(), rhs.to_big_integer()) else {
+            return Self(self.operand().to_float62() / rhs.operand().to_float62());
+        };
+
+        if y.is_zero() || !(&x % &y).is_zero() {
+            Self(self.operand().to_float62() / rhs.operand().to_float62())
+        } else {
+            Self::from_big_integer(x / y)
+        }
+    }
+}
+
+impl Neg for &BigFloat62 {
+    type Output = BigFloat62;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        match self.operand() {
+            Operand::Integer(integer) => BigFloat62::from_integer(-integer),
+            Operand::BigInteger(integer) => BigFloat62::from_big_integer(-integer),
+            Operand::Float(number) => BigFloat62(-number),
+        }
+    }
+}
+
+impl Neg for BigFloat62 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+fn compare_big_integer_and_float(integer: &BigInt, float: f64) -> Option<Ordering> {
+    if float.is_infinite() {
+        return Some(if float > 0.0 {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        });
+    }
+
+    let truncated = BigInt::from_f64(float)?;
+
+    match integer.cmp(&truncated) {
+        Ordering::Equal => truncated.to_f64()?.partial_cmp(&float),
+        ordering => Some(ordering),
+    }
+}
+
+impl PartialEq for BigFloat62 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for BigFloat62 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.operand(), other.operand()) {
+            (Operand::BigInteger(x), Operand::Float(y)) => {
+                compare_big_integer_and_float(x, y.to_float()?)
+            }
+            (Operand::Float(x), Operand::BigInteger(y)) => {
+                compare_big_integer_and_float(y, x.to_float()?).map(Ordering::reverse)
+            }
+            (x @ Operand::BigInteger(_), y) | (x, y @ Operand::BigInteger(_)) => {
+                x.to_big_integer()?.partial_cmp(&y.to_big_integer()?)
+            }
+            (x, y) => x.to_float62().partial_cmp(&y.to_float62()),
+        }
+    }
+}
+
+impl Debug for BigFloat62 {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self.big_integer() {
+            Some(integer) => formatter.debug_tuple("BigFloat62").field(integer).finish(),
+            None => formatter.debug_tuple("BigFloat62").field(&self.0).finish(),
+        }
+    }
+}
+
+impl Display for BigFloat62 {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self.big_integer() {
+            Some(integer) => write!(formatter, "{integer}"),
+            None => write!(formatter, "{}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn big(integer: i128) -> BigFloat62 {
+        BigFloat62::from_big_integer(integer.into())
+    }
+
+    #[test]
+    fn integer() {
+        for integer in [0, 1, -1, 42, INTEGER_MAXIMUM, INTEGER_MINIMUM] {
+            assert!(!BigFloat62::from_integer(integer).is_big_integer());
+            assert_eq!(
+                BigFloat62::from_integer(integer).to_integer(),
+                Some(integer)
+            );
+        }
+
+        for integer in [INTEGER_MAXIMUM + 1, INTEGER_MINIMUM - 1, i64::MAX, i64::MIN] {
+            assert!(BigFloat62::from_integer(integer).is_big_integer());
+            assert!(BigFloat62::from_integer(integer).is_integer());
+            assert_eq!(
+                BigFloat62::from_integer(integer).to_integer(),
+                Some(integer)
+            );
+            assert_eq!(BigFloat62::from_integer(integer).to_float(), None);
+        }
+    }
+
+    #[test]
+    fn demote_big_integer() {
+        assert!(!big(42).is_big_integer());
+        assert!(big(1 << 62).is_big_integer());
+        assert!(!(big(1 << 62) - big(1)).is_big_integer());
+        assert_eq!(
+            big(1 << 62) - big(1),
+            BigFloat62::from_integer(INTEGER_MAXIMUM)
+        );
+    }
+
+    #[test]
+    fn clone() {
+        let x = big(1 << 100);
+        let y = x.clone();
+
+        drop(x);
+
+        assert_eq!(y, big(1 << 100));
+    }
+
+    #[test]
+    fn float() {
+        assert_eq!(BigFloat62::from_float(4.2).to_float(), Some(4.2));
+        assert_eq!(BigFloat62::from_float(4.2).to_integer(), None);
+        assert_eq!(
+            BigFloat62::from_float(4.2).to_float62(),
+            Some(Float62::from_float(4.2))
+        );
+        assert_eq!(big(1 << 100).to_float62(), None);
+        assert!(
+            BigFloat62::from(Float62::from_payload(42))
+                .to_float()
+                .unwrap()
+                .is_nan()
+        );
+    }
+
+    #[test]
+    fn promote_on_overflow() {
+        let maximum = BigFloat62::from_integer(INTEGER_MAXIMUM);
+        let minimum = BigFloat62::from_integer(INTEGER_MINIMUM);
+
+        assert_eq!(
+            &maximum + &BigFloat62::from_integer(1),
+            big(INTEGER_MAXIMUM as i128 + 1)
+        );
+        assert_eq!(
+            &minimum - &BigFloat62::from_integer(1),
+            big(INTEGER_MINIMUM as i128 - 1)
+        );
+        assert_eq!(
+            &maximum * &maximum,
+            big(INTEGER_MAXIMUM as i128 * INTEGER_MAXIMUM as i128)
+        );
+        assert_eq!(-&minimum, big(-(INTEGER_MINIMUM as i128)));
+    }
+
+    #[test]
+    fn big_arithmetic() {
+        assert_eq!(big(1 << 100) + big(1 << 100), big(1 << 101));
+        assert_eq!(big(1 << 100) - big(1 << 100), BigFloat62::from_integer(0));
+        assert_eq!(big(1 << 100) * BigFloat62::from_integer(2), big(1 << 101));
+        assert_eq!(big(1 << 100) / big(1 << 98), BigFloat62::from_integer(4));
+        assert_eq!(-big(1 << 100), big(-(1 << 100)));
+        assert_eq!(
+            (BigFloat62::from_integer(1) / big(1 << 100)).to_float(),
+            Some(1.0 / (1u128 << 100) as f64)
+        );
+        assert_eq!(
+            (big(1 << 100) / BigFloat62::from_integer(0)).to_float(),
+            Some(f64::INFINITY)
+        );
+    }
+
+    #[test]
+    fn mixed_arithmetic() {
+        assert_eq!(
+            (big(1 << 100) + BigFloat62::from_float(0.5)).to_float(),
+            Some((1u128 << 100) as f64)
+        );
+        assert_eq!(
+            (BigFloat62::from_float(2.0) * big(1 << 100)).to_float(),
+            Some((1u128 << 101) as f64)
+        );
+        assert_eq!(
+            BigFloat62::from_integer(3) / BigFloat62::from_integer(2),
+            BigFloat62::from_float(1.5)
+        );
+    }
+
+    #[test]
+    fn cmp() {
+        assert_eq!(
+            big(1 << 100).partial_cmp(&BigFloat62::from_integer(0)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            big(-(1 << 100)).partial_cmp(&big(1 << 100)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(big(1 << 100), BigFloat62::from_float((1u128 << 100) as f64));
+        assert_eq!(
+            big((1 << 100) + 1).partial_cmp(&BigFloat62::from_float((1u128 << 100) as f64)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            BigFloat62::from_float(1e100).partial_cmp(&big(1 << 100)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            big(1 << 100).partial_cmp(&BigFloat62::from_float(f64::INFINITY)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            big(1 << 100).partial_cmp(&BigFloat62::from_float(f64::NAN)),
+            None
+        );
+        assert_eq!(
+            BigFloat62::from_integer(1).partial_cmp(&BigFloat62::from_float(1.5)),
+            Some(Ordering::Less)
+        );
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(big(1 << 100).to_string(), "1267650600228229401496703205376");
+        assert_eq!(BigFloat62::from_integer(42).to_string(), "42");
+        assert_eq!(BigFloat62::from_float(4.2).to_string(), "4.2");
+    }
+}
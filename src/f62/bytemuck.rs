@@ -0,0 +1,82 @@
+use super::{Float62, NICHE, is_canonical};
+use ::bytemuck::{CheckedBitPattern, NoUninit};
+
+// SAFETY: `Float62` is a transparent wrapper of `NonZeroU64` without any
+// padding bytes.
+unsafe impl NoUninit for Float62 {}
+
+// Bit patterns are in-memory representations of non-zero integers which are
+// raw representations exclusive-ored with the reserved one.
+unsafe impl CheckedBitPattern for Float62 {
+    type Bits = u64;
+
+    #[inline]
+    fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
+        *bits != 0 && is_canonical(bits ^ NICHE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::bytemuck::checked;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn cast_slice() {
+        let numbers = [
+            Float62::from_integer(42),
+            Float62::from_float(4.2),
+            Float62::from_payload(42),
+            Float62::from_float(f64::NAN),
+            Float62::NA,
+        ];
+        let bits = checked::cast_slice::<Float62, u64>(&numbers);
+
+        assert_eq!(
+            checked::try_cast_slice::<u64, Float62>(bits)
+                .unwrap()
+                .iter()
+                .map(|number| number.to_bits())
+                .collect::<Vec<_>>(),
+            numbers.map(Float62::to_bits)
+        );
+    }
+
+    #[test]
+    fn convert_raw_bytes() {
+        let numbers = [
+            Float62::from_integer(1),
+            Float62::from_float(-4.2),
+            Float62::from_payload(42),
+            Float62::NA,
+        ];
+        let images = checked::cast_slice::<Float62, [u8; 8]>(&numbers);
+
+        for (number, image) in numbers.iter().zip(images) {
+            let bits = u64::from_le_bytes(number.to_le_bytes());
+
+            assert_eq!(*image, (bits ^ NICHE).to_ne_bytes());
+            assert_eq!(
+                checked::try_from_bytes::<Float62>(&(bits ^ NICHE).to_ne_bytes())
+                    .map(|number| number.to_bits()),
+                Ok(bits)
+            );
+        }
+    }
+
+    #[test]
+    fn reject_non_canonical_images() {
+        let bits = (4 << 3) | 0b101;
+
+        assert!(checked::try_from_bytes::<Float62>(&(bits ^ NICHE).to_ne_bytes()).is_err());
+        assert!(Float62::try_from_bytes(bits.to_le_bytes()).is_err());
+    }
+
+    #[test]
+    fn reject_invalid_bit_patterns() {
+        assert!(checked::try_cast_slice::<u64, Float62>(&[0]).is_err());
+        assert!(checked::try_cast_slice::<u64, Float62>(&[(4 << 3 | 0b101) ^ NICHE]).is_err());
+        assert!(checked::try_cast_slice::<u64, Float62>(&[42 << 1 ^ NICHE]).is_ok());
+    }
+}
@@ -0,0 +1,799 @@
+//! Inline decimal numbers.
+//!
+//! A decimal number is packed into the 59 bits left by the 3-bit special tag
+//! and the 2-bit subtag of special values. They are split into a 54-bit signed
+//! mantissa, which is exactly representable by 64-bit floating-point numbers,
+//! and a 5-bit scale of fractional digits.
+
+use super::{Float62, SPECIAL_TAG, compare_integers};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Formatter},
+};
+
+const DECIMAL_TAG: u64 = (1 << 63) | SPECIAL_TAG;
+const TAG_MASK: u64 = (0b11 << 62) | 0b111;
+const MANTISSA_SHIFT: u32 = 8;
+const MANTISSA_LIMIT: i128 = 1 << 53;
+const SCALE_MASK: u64 = (1 << 5) - 1;
+const MAXIMUM_SCALE: u32 = SCALE_MASK as _;
+const INTEGER_LIMIT: i128 = 1 << 62;
+const MAXIMUM_EXACT_POWER: u32 = 22;
+const FRACTION_LIMIT: i128 = 1 << 52;
+
+/// A rounding mode of decimal numbers.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Rounding {
+    /// Rounds towards zero.
+    Down,
+    /// Rounds away from zero.
+    Up,
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards positive infinity.
+    Ceiling,
+    /// Rounds to the nearest neighbor or towards zero if both neighbors are
+    /// equidistant.
+    HalfDown,
+    /// Rounds to the nearest neighbor or away from zero if both neighbors are
+    /// equidistant.
+    HalfUp,
+    /// Rounds to the nearest neighbor or to the even neighbor if both
+    /// neighbors are equidistant.
+    #[default]
+    HalfEven,
+}
+
+/// Boxes a decimal number of a 54-bit signed mantissa and a 5-bit scale.
+///
+/// The decimal number represents `mantissa * 10^-scale`. The mantissa must not
+/// be divisible by 10 and the scale must be greater than 0.
+#[inline]
+pub const fn box_decimal(mantissa: i64, scale: u32) -> u64 {
+    DECIMAL_TAG
+        | ((mantissa as u64) << MANTISSA_SHIFT) & !TAG_MASK
+        | (scale as u64 & SCALE_MASK) << 3
+}
+
+/// Unboxes a decimal number into its mantissa and scale.
+#[inline]
+pub const fn unbox_decimal(number: u64) -> Option<(i64, u32)> {
+    if is_decimal(number) {
+        Some(unbox_decimal_unchecked(number))
+    } else {
+        None
+    }
+}
+
+/// Unboxes a decimal number into its mantissa and scale without any type
+/// check.
+///
+/// The type is asserted in debug builds with the `strict` feature.
+#[inline]
+#[cfg_attr(feature = "strict", track_caller)]
+pub const fn unbox_decimal_unchecked(number: u64) -> (i64, u32) {
+    debug_assert!(!cfg!(feature = "strict") || is_decimal(number));
+
+    (
+        (number << 2) as i64 >> (MANTISSA_SHIFT + 2),
+        (number >> 3 & SCALE_MASK) as _,
+    )
+}
+
+/// Returns `true` if a number is a decimal number.
+#[inline]
+pub const fn is_decimal(number: u64) -> bool {
+    number & TAG_MASK == DECIMAL_TAG
+}
+
+const fn power(scale: u32) -> Option<i128> {
+    10i128.checked_pow(scale)
+}
+
+const fn to_float(mantissa: i128, mut scale: u32) -> f64 {
+    let mut number = mantissa as f64;
+
+    while scale > MAXIMUM_EXACT_POWER {
+        number /= 1e22;
+        scale -= MAXIMUM_EXACT_POWER;
+    }
+
+    number / 10u128.pow(scale) as f64
+}
+
+const fn divide(lhs: i128, rhs: i128, rounding: Rounding) -> i128 {
+    let quotient = lhs / rhs;
+    let remainder = lhs % rhs;
+
+    if remainder == 0 {
+        return quotient;
+    }
+
+    let negative = (lhs < 0) != (rhs < 0);
+    let away = quotient + if negative { -1 } else { 1 };
+    let remainder = remainder.unsigned_abs() * 2;
+    let divisor = rhs.unsigned_abs();
+    let half = if remainder < divisor {
+        Ordering::Less
+    } else if remainder > divisor {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    };
+
+    match (rounding, half) {
+        (Rounding::Down, _)
+        | (Rounding::HalfDown | Rounding::HalfUp | Rounding::HalfEven, Ordering::Less) => quotient,
+        (Rounding::Up, _)
+        | (Rounding::HalfDown | Rounding::HalfUp | Rounding::HalfEven, Ordering::Greater) => away,
+        (Rounding::Floor, _) => {
+            if negative {
+                away
+            } else {
+                quotient
+            }
+        }
+        (Rounding::Ceiling, _) => {
+            if negative {
+                quotient
+            } else {
+                away
+            }
+        }
+        (Rounding::HalfDown, Ordering::Equal) => quotient,
+        (Rounding::HalfUp, Ordering::Equal) => away,
+        (Rounding::HalfEven, Ordering::Equal) => {
+            if quotient % 2 == 0 {
+                quotient
+            } else {
+                away
+            }
+        }
+    }
+}
+
+impl Float62 {
+    /// Creates a 62-bit floating-point number from a decimal number of
+    /// `mantissa * 10^-scale`.
+    ///
+    /// The result is an integer if it has no fractional digits, a decimal
+    /// number if its normalized components fit, and a floating-point number
+    /// otherwise.
+    #[inline]
+    pub const fn from_decimal(mantissa: i64, scale: u32) -> Self {
+        Self::from_scaled(mantissa as _, scale)
+    }
+
+    /// Returns a mantissa and a scale of a decimal number.
+    #[inline]
+    pub const fn to_decimal(self) -> Option<(i64, u32)> {
+        unbox_decimal(self.to_bits())
+    }
+
+    /// Returns a mantissa and a scale of a decimal number without any type
+    /// check.
+    #[inline]
+    #[cfg_attr(feature = "strict", track_caller)]
+    pub const fn to_decimal_unchecked(self) -> (i64, u32) {
+        unbox_decimal_unchecked(self.to_bits())
+    }
+
+    /// Returns `true` if this number is a non-integer decimal number.
+    #[inline]
+    pub const fn is_decimal(self) -> bool {
+        is_decimal(self.to_bits())
+    }
+
+    /// Divides this number by another number rounding the quotient to `scale`
+    /// fractional digits.
+    ///
+    /// It falls back to the division operator unless both numbers are
+    /// integers or decimal numbers and the divisor is not zero.
+    #[inline]
+    pub const fn div_decimal(self, rhs: Self, scale: u32, rounding: Rounding) -> Self {
+        match self.try_div_decimal(rhs, scale, rounding) {
+            Some(number) => number,
+            None => self.const_div(rhs),
+        }
+    }
+
+    const fn try_div_decimal(self, rhs: Self, scale: u32, rounding: Rounding) -> Option<Self> {
+        let (Some((x, x_scale)), Some((y, y_scale))) = (self.to_scaled(), rhs.to_scaled()) else {
+            return None;
+        };
+
+        if y == 0 {
+            return None;
+        }
+
+        let Some(exponent) = y_scale.checked_add(scale) else {
+            return None;
+        };
+        let (Some(x_power), Some(y_power)) = (power(exponent), power(x_scale)) else {
+            return None;
+        };
+        let (Some(x), Some(y)) = (x.checked_mul(x_power), y.checked_mul(y_power)) else {
+            return None;
+        };
+
+        Some(Self::from_scaled(divide(x, y, rounding), scale))
+    }
+
+    /// Rounds this number to a decimal number of at most `scale` fractional
+    /// digits.
+    ///
+    /// Floating-point numbers are converted into decimal numbers. Infinities,
+    /// NaN, and payloads are returned as they are.
+    #[inline]
+    pub fn round_decimal(self, scale: u32, rounding: Rounding) -> Self {
+        if let Some((mantissa, current)) = self.to_scaled() {
+            if current <= scale {
+                return self;
+            }
+
+            return power(current - scale).map_or(self, |power| {
+                Self::from_scaled(divide(mantissa, power, rounding), scale)
+            });
+        }
+
+        let Some(number) = self.to_float() else {
+            return self;
+        };
+        let Some(power) = power(scale) else {
+            return self;
+        };
+        let number = number * power as f64;
+
+        if !number.is_finite() || number.abs() >= INTEGER_LIMIT as f64 {
+            return self;
+        }
+
+        // Represent the number as a binary fixed-point number so that ties are
+        // rounded by integer division.
+        let truncated = number as i128;
+        let fraction = match ((number - truncated as f64) * FRACTION_LIMIT as f64) as i128 {
+            0 if number < truncated as f64 => -1,
+            0 if number > truncated as f64 => 1,
+            fraction => fraction,
+        };
+
+        Self::from_scaled(
+            divide(
+                truncated * FRACTION_LIMIT + fraction,
+                FRACTION_LIMIT,
+                rounding,
+            ),
+            scale,
+        )
+    }
+
+    pub(super) const fn from_scaled(mut mantissa: i128, mut scale: u32) -> Self {
+        while scale > 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+
+        if scale == 0 && -INTEGER_LIMIT <= mantissa && mantissa < INTEGER_LIMIT {
+            Self::from_integer(mantissa as _)
+        } else if scale != 0
+            && scale <= MAXIMUM_SCALE
+            && -MANTISSA_LIMIT <= mantissa
+            && mantissa < MANTISSA_LIMIT
+        {
+            Self::from_bits(box_decimal(mantissa as _, scale))
+        } else {
+            Self::from_float(to_float(mantissa, scale))
+        }
+    }
+
+    pub(super) const fn to_scaled(self) -> Option<(i128, u32)> {
+        if let Some(integer) = self.to_integer() {
+            Some((integer as _, 0))
+        } else if let Some((mantissa, scale)) = self.to_decimal() {
+            Some((mantissa as _, scale))
+        } else {
+            None
+        }
+    }
+
+    pub(super) const fn to_decimal_float(self) -> Option<f64> {
+        if let Some((mantissa, scale)) = self.to_decimal() {
+            Some(to_float(mantissa as _, scale))
+        } else {
+            None
+        }
+    }
+
+    pub(super) fn fmt_decimal(self, formatter: &mut Formatter<'_>) -> Option<fmt::Result> {
+        let (mantissa, scale) = self.to_decimal()?;
+        let power = 10u128.pow(scale);
+        let magnitude = u128::from(mantissa.unsigned_abs());
+
+        Some(write!(
+            formatter,
+            "{}{}.{:0width$}",
+            if mantissa < 0 { "-" } else { "" },
+            magnitude / power,
+            magnitude % power,
+            width = scale as usize
+        ))
+    }
+}
+
+const fn align(x: i128, x_scale: u32, y: i128, y_scale: u32) -> Option<(i128, i128, u32)> {
+    let scale = if x_scale > y_scale { x_scale } else { y_scale };
+    let (Some(x_power), Some(y_power)) = (power(scale - x_scale), power(scale - y_scale)) else {
+        return None;
+    };
+    let (Some(x), Some(y)) = (x.checked_mul(x_power), y.checked_mul(y_power)) else {
+        return None;
+    };
+
+    Some((x, y, scale))
+}
+
+pub(super) const fn add(lhs: Float62, rhs: Float62) -> Option<Float62> {
+    let (Some((x, x_scale)), Some((y, y_scale))) = (lhs.to_scaled(), rhs.to_scaled()) else {
+        return None;
+    };
+    let Some((x, y, scale)) = align(x, x_scale, y, y_scale) else {
+        return None;
+    };
+
+    Some(Float62::from_scaled(x + y, scale))
+}
+
+pub(super) const fn sub(lhs: Float62, rhs: Float62) -> Option<Float62> {
+    let (Some((x, x_scale)), Some((y, y_scale))) = (lhs.to_scaled(), rhs.to_scaled()) else {
+        return None;
+    };
+    let Some((x, y, scale)) = align(x, x_scale, y, y_scale) else {
+        return None;
+    };
+
+    Some(Float62::from_scaled(x - y, scale))
+}
+
+pub(super) const fn mul(lhs: Float62, rhs: Float62) -> Option<Float62> {
+    let (Some((x, x_scale)), Some((y, y_scale))) = (lhs.to_scaled(), rhs.to_scaled()) else {
+        return None;
+    };
+
+    Some(Float62::from_scaled(x * y, x_scale + y_scale))
+}
+
+pub(super) const fn div(lhs: Float62, rhs: Float62) -> Option<Float62> {
+    let (Some((x, x_scale)), Some((y, y_scale))) = (lhs.to_scaled(), rhs.to_scaled()) else {
+        return None;
+    };
+
+    if y == 0 {
+        return None;
+    }
+
+    let Some(x_power) = power(y_scale + MAXIMUM_SCALE) else {
+        return None;
+    };
+    let Some(y_power) = power(x_scale) else {
+        return None;
+    };
+    let (Some(x), Some(y)) = (x.checked_mul(x_power), y.checked_mul(y_power)) else {
+        return None;
+    };
+
+    if x % y == 0 {
+        Some(Float62::from_scaled(x / y, MAXIMUM_SCALE))
+    } else {
+        None
+    }
+}
+
+pub(super) const fn compare(lhs: Float62, rhs: Float62) -> Option<Ordering> {
+    let (Some((x, x_scale)), Some((y, y_scale))) = (lhs.to_scaled(), rhs.to_scaled()) else {
+        return None;
+    };
+
+    Some(match align(x, x_scale, y, y_scale) {
+        Some((x, y, _)) => compare_integers(x, y),
+        // An aligned mantissa overflows only when its magnitude exceeds the other's.
+        None if x_scale < y_scale => compare_integers(x, 0),
+        None => compare_integers(0, y),
+    })
+}
+
+// Parses a string of digits with an optional sign and a decimal point into an
+// integer or a decimal number.
+pub(super) fn parse(string: &str) -> Option<Float62> {
+    let (negative, digits) = match string.as_bytes() {
+        [b'-', digits @ ..] => (true, digits),
+        [b'+', digits @ ..] => (false, digits),
+        digits => (false, digits),
+    };
+    let mut mantissa = 0i128;
+    let mut scale = None::<u32>;
+    let mut empty = true;
+
+    for &digit in digits {
+        if digit == b'.' && scale.is_none() {
+            scale = Some(0);
+        } else if digit.is_ascii_digit() {
+            mantissa = mantissa
+                .checked_mul(10)?
+                .checked_add((digit - b'0').into())?;
+            scale = scale.map(|scale| scale + 1);
+            empty = false;
+        } else {
+            return None;
+        }
+    }
+
+    if empty {
+        return None;
+    }
+
+    Some(Float62::from_scaled(
+        if negative { -mantissa } else { mantissa },
+        scale.unwrap_or_default(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f62::{
+        Float62Kind, NAN, box_float, box_integer, box_payload, is_float, is_infinite, is_integer,
+        is_nan, is_payload,
+    };
+    use alloc::string::ToString;
+
+    fn decimal(mantissa: i64, scale: u32) -> Float62 {
+        Float62::from_decimal(mantissa, scale)
+    }
+
+    fn parse(string: &str) -> Float62 {
+        string.parse().unwrap()
+    }
+
+    #[test]
+    fn decimal_bits() {
+        for (mantissa, scale) in [
+            (1, 1),
+            (-1, 1),
+            (12345, 3),
+            ((1 << 53) - 1, 31),
+            (-(1 << 53), 7),
+        ] {
+            let number = box_decimal(mantissa, scale);
+
+            assert!(is_decimal(number));
+            assert!(!is_integer(number));
+            assert!(!is_float(number));
+            assert!(!is_payload(number));
+            assert!(!is_nan(number));
+            assert!(!is_infinite(number));
+            assert_eq!(unbox_decimal(number), Some((mantissa, scale)));
+        }
+
+        assert_eq!(unbox_decimal(box_integer(42)), None);
+        assert_eq!(unbox_decimal(box_float(0.5)), None);
+        assert_eq!(unbox_decimal(box_payload(42)), None);
+        assert_eq!(unbox_decimal(NAN), None);
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(decimal(10, 2).to_decimal(), Some((1, 1)));
+        assert_eq!(decimal(-1500, 3).to_decimal(), Some((-15, 1)));
+        assert_eq!(decimal(100, 2).to_integer(), Some(1));
+        assert_eq!(decimal(0, 5).to_integer(), Some(0));
+        assert_eq!(decimal(42, 0).to_integer(), Some(42));
+    }
+
+    #[test]
+    fn fall_back_to_float() {
+        assert_eq!(
+            decimal(1 << 53, 1).to_float(),
+            Some((1u64 << 53) as f64 / 10.0)
+        );
+        assert_eq!(decimal(1, 32).to_float(), Some(1e-32));
+    }
+
+    #[test]
+    fn predicates() {
+        assert!(decimal(1, 1).is_decimal());
+        assert!(decimal(1, 1).is_finite());
+        assert!(decimal(1, 1).is_number());
+        assert!(!decimal(1, 1).is_integer());
+        assert!(!decimal(1, 1).is_float());
+        assert!(!decimal(1, 1).is_nan());
+    }
+
+    #[test]
+    fn classify() {
+        assert_eq!(
+            decimal(-15, 1).classify(),
+            Float62Kind::Decimal {
+                mantissa: -15,
+                scale: 1
+            }
+        );
+        assert_eq!(
+            Float62::from(Float62Kind::Decimal {
+                mantissa: 150,
+                scale: 2
+            })
+            .to_decimal(),
+            Some((15, 1))
+        );
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!((decimal(1, 1) + decimal(2, 1)).to_decimal(), Some((3, 1)));
+        assert_eq!(decimal(1, 1) + decimal(2, 1), decimal(3, 1));
+        assert_eq!((decimal(5, 1) + decimal(5, 1)).to_integer(), Some(1));
+        assert_eq!(
+            (decimal(1, 1) + Float62::from_integer(1)).to_decimal(),
+            Some((11, 1))
+        );
+        assert_eq!((decimal(1, 2) + decimal(1, 1)).to_decimal(), Some((11, 2)));
+        assert_eq!(
+            (decimal(5, 1) + Float62::from_float(0.25)).to_float(),
+            Some(0.75)
+        );
+    }
+
+    #[test]
+    fn sub() {
+        assert_eq!((decimal(3, 1) - decimal(1, 1)).to_decimal(), Some((2, 1)));
+        assert_eq!(
+            (Float62::from_integer(1) - decimal(1, 2)).to_decimal(),
+            Some((99, 2))
+        );
+        assert_eq!((decimal(3, 1) - decimal(3, 1)).to_integer(), Some(0));
+        assert_eq!(
+            (Float62::from_integer(1 << 40) - decimal(1, 31)).to_float(),
+            Some((1u64 << 40) as f64)
+        );
+    }
+
+    #[test]
+    fn mul() {
+        assert_eq!((decimal(15, 1) * decimal(2, 1)).to_decimal(), Some((3, 1)));
+        assert_eq!(
+            (decimal(25, 1) * Float62::from_integer(2)).to_integer(),
+            Some(5)
+        );
+        assert!((decimal(1, 20) * decimal(1, 20)).is_float());
+    }
+
+    #[test]
+    fn div() {
+        assert_eq!((decimal(3, 1) / decimal(2, 1)).to_decimal(), Some((15, 1)));
+        assert_eq!(
+            (Float62::from_integer(1) / decimal(4, 1)).to_decimal(),
+            Some((25, 1))
+        );
+        assert_eq!((decimal(6, 1) / decimal(3, 1)).to_integer(), Some(2));
+        assert_eq!(
+            (decimal(1, 1) / Float62::from_integer(3)).to_float(),
+            Some(0.1 / 3.0)
+        );
+        assert_eq!(
+            (decimal(1, 1) / Float62::from_integer(0)).to_float(),
+            Some(f64::INFINITY)
+        );
+    }
+
+    #[test]
+    fn div_decimal() {
+        let one = Float62::from_integer(1);
+        let three = Float62::from_integer(3);
+        let two = Float62::from_integer(2);
+
+        assert_eq!(
+            one.div_decimal(three, 4, Rounding::HalfEven).to_decimal(),
+            Some((3333, 4))
+        );
+        assert_eq!(
+            two.div_decimal(three, 4, Rounding::HalfEven).to_decimal(),
+            Some((6667, 4))
+        );
+        assert_eq!(
+            two.div_decimal(three, 4, Rounding::Down).to_decimal(),
+            Some((6666, 4))
+        );
+        assert_eq!(
+            (-two).div_decimal(three, 4, Rounding::Floor).to_decimal(),
+            Some((-6667, 4))
+        );
+        assert_eq!(
+            (-two).div_decimal(three, 4, Rounding::Ceiling).to_decimal(),
+            Some((-6666, 4))
+        );
+        assert_eq!(
+            decimal(1, 1)
+                .div_decimal(three, 2, Rounding::Up)
+                .to_decimal(),
+            Some((4, 2))
+        );
+        assert_eq!(
+            one.div_decimal(Float62::from_integer(0), 2, Rounding::HalfEven)
+                .to_float(),
+            Some(f64::INFINITY)
+        );
+        assert_eq!(
+            Float62::from_float(1.0)
+                .div_decimal(three, 2, Rounding::HalfEven)
+                .to_float(),
+            Some(1.0 / 3.0)
+        );
+    }
+
+    #[test]
+    fn round_ties() {
+        let cases = [
+            (Rounding::Down, [2, 2, -2, -2]),
+            (Rounding::Up, [3, 3, -3, -3]),
+            (Rounding::Floor, [2, 2, -3, -3]),
+            (Rounding::Ceiling, [3, 3, -2, -2]),
+            (Rounding::HalfDown, [2, 3, -2, -3]),
+            (Rounding::HalfUp, [3, 3, -3, -3]),
+            (Rounding::HalfEven, [2, 3, -2, -3]),
+        ];
+
+        for (rounding, expected) in cases {
+            for (mantissa, expected) in [25, 26, -25, -26].into_iter().zip(expected) {
+                assert_eq!(
+                    decimal(mantissa, 1).round_decimal(0, rounding).to_integer(),
+                    Some(expected),
+                    "{rounding:?} {mantissa}"
+                );
+            }
+        }
+
+        assert_eq!(
+            decimal(35, 1)
+                .round_decimal(0, Rounding::HalfEven)
+                .to_integer(),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn round_decimal() {
+        assert_eq!(
+            decimal(12345, 4)
+                .round_decimal(2, Rounding::HalfEven)
+                .to_decimal(),
+            Some((123, 2))
+        );
+        assert_eq!(
+            decimal(12345, 4)
+                .round_decimal(5, Rounding::HalfEven)
+                .to_decimal(),
+            Some((12345, 4))
+        );
+        assert_eq!(
+            Float62::from_integer(42)
+                .round_decimal(2, Rounding::HalfEven)
+                .to_integer(),
+            Some(42)
+        );
+        assert_eq!(
+            Float62::from_float(0.1)
+                .round_decimal(2, Rounding::HalfEven)
+                .to_decimal(),
+            Some((1, 1))
+        );
+        assert_eq!(
+            Float62::from_float(-1.005)
+                .round_decimal(1, Rounding::HalfUp)
+                .to_integer(),
+            Some(-1)
+        );
+        assert_eq!(
+            Float62::from_float(2.5)
+                .round_decimal(0, Rounding::HalfEven)
+                .to_integer(),
+            Some(2)
+        );
+        assert_eq!(
+            Float62::from_float(-2.5)
+                .round_decimal(0, Rounding::HalfUp)
+                .to_integer(),
+            Some(-3)
+        );
+        assert!(
+            Float62::from_float(f64::NAN)
+                .round_decimal(2, Rounding::HalfEven)
+                .is_nan()
+        );
+        assert_eq!(
+            Float62::from_float(1e70)
+                .round_decimal(2, Rounding::HalfEven)
+                .to_float(),
+            Some(1e70)
+        );
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!((-decimal(15, 1)).to_decimal(), Some((-15, 1)));
+        assert_eq!((-decimal(-15, 1)).to_decimal(), Some((15, 1)));
+    }
+
+    #[test]
+    fn cmp() {
+        assert_eq!(decimal(1, 1) + decimal(2, 1), parse("0.3"));
+        assert_eq!(
+            decimal(1, 1).partial_cmp(&decimal(11, 2)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            decimal(-1, 1).partial_cmp(&Float62::from_integer(0)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            Float62::from_integer(1 << 61).partial_cmp(&decimal(1, 31)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            decimal(1, 31).partial_cmp(&Float62::from_integer(-(1 << 61))),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(decimal(5, 1), Float62::from_float(0.5));
+        assert_eq!(
+            decimal(5, 1).partial_cmp(&Float62::from_float(f64::NAN)),
+            None
+        );
+    }
+
+    #[test]
+    fn canonical_bytes() {
+        assert!(Float62::try_from_bytes(box_decimal(15, 1).to_le_bytes()).is_ok());
+        assert!(Float62::try_from_bytes(box_decimal(150, 2).to_le_bytes()).is_err());
+        assert!(Float62::try_from_bytes(box_decimal(15, 0).to_le_bytes()).is_err());
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(decimal(1, 1).to_string(), "0.1");
+        assert_eq!(decimal(-15, 1).to_string(), "-1.5");
+        assert_eq!(decimal(12345, 3).to_string(), "12.345");
+        assert_eq!(decimal(-1, 3).to_string(), "-0.001");
+    }
+
+    #[test]
+    fn parse_string() {
+        assert_eq!(parse("0.1").to_decimal(), Some((1, 1)));
+        assert_eq!(parse("-1.50").to_decimal(), Some((-15, 1)));
+        assert_eq!(parse("+.5").to_decimal(), Some((5, 1)));
+        assert_eq!(parse("3.").to_integer(), Some(3));
+        assert_eq!(parse("42").to_integer(), Some(42));
+        assert_eq!(parse("-42").to_integer(), Some(-42));
+        assert_eq!(parse("1e3").to_float(), Some(1e3));
+        assert_eq!(parse("inf").to_float(), Some(f64::INFINITY));
+        assert!(parse("NaN").is_nan());
+        assert_eq!(parse("100000000000000000000").to_float(), Some(1e20));
+        assert!("".parse::<Float62>().is_err());
+        assert!(".".parse::<Float62>().is_err());
+        assert!("1.2.3".parse::<Float62>().is_err());
+        assert!("foo".parse::<Float62>().is_err());
+    }
+
+    #[test]
+    fn format_and_parse() {
+        for number in [
+            decimal(1, 1),
+            decimal(-12345, 3),
+            decimal(7, 31),
+            Float62::from_integer(-42),
+        ] {
+            assert_eq!(
+                number.to_string().parse::<Float62>().unwrap().to_bits(),
+                number.to_bits()
+            );
+        }
+    }
+}
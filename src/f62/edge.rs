@@ -0,0 +1,33 @@
+use super::{Float62, MANTISSA_WIDTH, MAXIMUM_EXPONENT, MINIMUM_EXPONENT};
+
+pub(super) const INTEGER_MINIMUM: i64 = -(1 << 62);
+pub(super) const INTEGER_MAXIMUM: i64 = (1 << 62) - 1;
+const PAYLOAD_MAXIMUM: u64 = (1 << 61) - 1;
+const FLOAT_MINIMUM: f64 = f64::from_bits(MINIMUM_EXPONENT << MANTISSA_WIDTH);
+const FLOAT_MAXIMUM: f64 =
+    f64::from_bits((MAXIMUM_EXPONENT << MANTISSA_WIDTH) | ((1 << MANTISSA_WIDTH) - 1));
+
+// Numbers at boundaries of encodings.
+pub(super) const EDGE_CASES: [Float62; 21] = [
+    Float62::from_integer(0),
+    Float62::from_integer(1),
+    Float62::from_integer(-1),
+    Float62::from_integer(INTEGER_MINIMUM),
+    Float62::from_integer(INTEGER_MAXIMUM),
+    Float62::from_integer(INTEGER_MINIMUM + 1),
+    Float62::from_integer(INTEGER_MAXIMUM - 1),
+    Float62::from_float(INTEGER_MINIMUM as f64),
+    Float62::from_float(-(INTEGER_MINIMUM as f64)),
+    Float62::from_float(FLOAT_MINIMUM),
+    Float62::from_float(-FLOAT_MINIMUM),
+    Float62::from_float(FLOAT_MAXIMUM),
+    Float62::from_float(-FLOAT_MAXIMUM),
+    Float62::from_float(0.5),
+    Float62::from_float(f64::INFINITY),
+    Float62::from_float(f64::NEG_INFINITY),
+    Float62::from_float(f64::NAN),
+    Float62::NA,
+    Float62::from_payload(0),
+    Float62::from_payload(1),
+    Float62::from_payload(PAYLOAD_MAXIMUM),
+];
@@ -0,0 +1,66 @@
+use super::{
+    Float62,
+    edge::{EDGE_CASES, INTEGER_MAXIMUM, INTEGER_MINIMUM},
+};
+use ::proptest::{
+    arbitrary::{Arbitrary, any},
+    prop_oneof,
+    sample::select,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+impl Arbitrary for Float62 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    #[inline]
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            2 => select(&EDGE_CASES[..]),
+            1 => (INTEGER_MINIMUM..=INTEGER_MAXIMUM).prop_map(Self::from_integer),
+            1 => any::<f64>().prop_map(Self::from_float),
+            1 => any::<u64>().prop_map(Self::from_bits),
+        ]
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::proptest::{
+        prop_assert, prop_assert_eq, proptest, strategy::ValueTree, test_runner::TestRunner,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn generate_edge_cases() {
+        let mut runner = TestRunner::deterministic();
+        let strategy = any::<Float62>();
+        let numbers = (0..1 << 12)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+            .collect::<Vec<_>>();
+
+        for edge_case in EDGE_CASES {
+            assert!(
+                numbers
+                    .iter()
+                    .any(|number| number.to_bits() == edge_case.to_bits())
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip_bits(number: Float62) {
+            prop_assert_eq!(Float62::from_bits(number.to_bits()).to_bits(), number.to_bits());
+        }
+
+        #[test]
+        fn operate(x: Float62, y: Float62) {
+            for z in [x + y, x - y, x * y, x / y, x % y, -x] {
+                prop_assert!(!z.is_payload() || x.is_payload() || y.is_payload());
+            }
+        }
+    }
+}
@@ -0,0 +1,12 @@
+// Generates pseudo-random raw representations deterministically with
+// xorshift.
+pub(super) fn random_bits() -> impl Iterator<Item = u64> {
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+
+    (0..1 << 16).map(move |_| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    })
+}
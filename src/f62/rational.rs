@@ -0,0 +1,413 @@
+//! Inline rational numbers.
+//!
+//! A rational number is packed into the 59 bits left by the 3-bit special tag
+//! and the 2-bit subtag of special values. They are split into a 30-bit
+//! signed numerator and a 29-bit denominator since wider components, such as
+//! 31-bit numerators and 30-bit denominators, do not fit.
+
+use super::{Float62, SPECIAL_TAG};
+
+const RATIONAL_TAG: u64 = (1 << 62) | SPECIAL_TAG;
+const TAG_MASK: u64 = (0b11 << 62) | 0b111;
+const NUMERATOR_SHIFT: u32 = 32;
+const NUMERATOR_LIMIT: i128 = 1 << 29;
+const DENOMINATOR_LIMIT: i128 = 1 << 29;
+const DENOMINATOR_MASK: u64 = (1 << 29) - 1;
+const INTEGER_LIMIT: i128 = 1 << 62;
+
+/// Boxes a rational number of a 30-bit signed numerator and a 29-bit
+/// denominator.
+///
+/// The rational number must be in its lowest terms with a denominator greater
+/// than 1.
+#[inline]
+pub const fn box_rational(numerator: i64, denominator: i64) -> u64 {
+    RATIONAL_TAG
+        | ((numerator as u64) << NUMERATOR_SHIFT) & !TAG_MASK
+        | (denominator as u64 & DENOMINATOR_MASK) << 3
+}
+
+/// Unboxes a rational number into its numerator and denominator.
+#[inline]
+pub const fn unbox_rational(number: u64) -> Option<(i64, i64)> {
+    if is_rational(number) {
+        Some(unbox_rational_unchecked(number))
+    } else {
+        None
+    }
+}
+
+/// Unboxes a rational number into its numerator and denominator without any
+/// type check.
+///
+/// The type is asserted in debug builds with the `strict` feature.
+#[inline]
+#[cfg_attr(feature = "strict", track_caller)]
+pub const fn unbox_rational_unchecked(number: u64) -> (i64, i64) {
+    debug_assert!(!cfg!(feature = "strict") || is_rational(number));
+
+    (
+        (number << 2) as i64 >> (NUMERATOR_SHIFT + 2),
+        (number >> 3 & DENOMINATOR_MASK) as _,
+    )
+}
+
+/// Returns `true` if a number is a rational number.
+#[inline]
+pub const fn is_rational(number: u64) -> bool {
+    number & TAG_MASK == RATIONAL_TAG
+}
+
+const fn gcd(mut x: u128, mut y: u128) -> u128 {
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+
+    x
+}
+
+impl Float62 {
+    /// Creates a 62-bit floating-point number from a numerator and a
+    /// denominator.
+    ///
+    /// The result is an integer if the denominator divides the numerator, a
+    /// rational number if its reduced components fit, and a floating-point
+    /// number otherwise.
+    #[inline]
+    pub const fn from_rational(numerator: i64, denominator: i64) -> Self {
+        Self::from_fraction(numerator as _, denominator as _)
+    }
+
+    /// Returns a numerator and a denominator of a rational number.
+    #[inline]
+    pub const fn to_rational(self) -> Option<(i64, i64)> {
+        unbox_rational(self.to_bits())
+    }
+
+    /// Returns a numerator and a denominator of a rational number without any
+    /// type check.
+    #[inline]
+    #[cfg_attr(feature = "strict", track_caller)]
+    pub const fn to_rational_unchecked(self) -> (i64, i64) {
+        unbox_rational_unchecked(self.to_bits())
+    }
+
+    /// Returns `true` if this number is a non-integer rational number.
+    #[inline]
+    pub const fn is_rational(self) -> bool {
+        is_rational(self.to_bits())
+    }
+
+    /// Returns `true` if this number is an integer or a rational number.
+    #[inline]
+    pub const fn is_exact(self) -> bool {
+        self.is_integer() || self.is_rational()
+    }
+
+    pub(super) const fn from_fraction(numerator: i128, denominator: i128) -> Self {
+        if denominator == 0 {
+            return Self::from_float(numerator as f64 / 0.0);
+        }
+
+        let divisor = gcd(numerator.unsigned_abs(), denominator.unsigned_abs()) as i128
+            * denominator.signum();
+        let numerator = numerator / divisor;
+        let denominator = denominator / divisor;
+
+        if denominator == 1 && -INTEGER_LIMIT <= numerator && numerator < INTEGER_LIMIT {
+            Self::from_integer(numerator as _)
+        } else if denominator != 1
+            && -NUMERATOR_LIMIT <= numerator
+            && numerator < NUMERATOR_LIMIT
+            && denominator < DENOMINATOR_LIMIT
+        {
+            Self::from_bits(box_rational(numerator as _, denominator as _))
+        } else {
+            Self::from_float(numerator as f64 / denominator as f64)
+        }
+    }
+
+    pub(super) const fn to_fraction(self) -> Option<(i128, i128)> {
+        if let Some(integer) = self.to_integer() {
+            Some((integer as _, 1))
+        } else if let Some((numerator, denominator)) = self.to_rational() {
+            Some((numerator as _, denominator as _))
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! operate {
+    ($name:ident, |$x:ident, $y:ident, $z:ident, $w:ident| $numerator:expr, $denominator:expr) => {
+        pub(super) const fn $name(lhs: Float62, rhs: Float62) -> Option<Float62> {
+            let (Some(($x, $y)), Some(($z, $w))) = (lhs.to_fraction(), rhs.to_fraction()) else {
+                return None;
+            };
+
+            Some(Float62::from_fraction($numerator, $denominator))
+        }
+    };
+}
+
+operate!(add, |x, y, z, w| x * w + z * y, y * w);
+operate!(sub, |x, y, z, w| x * w - z * y, y * w);
+operate!(mul, |x, y, z, w| x * z, y * w);
+operate!(div, |x, y, z, w| x * w, y * z);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f62::{
+        Float62Kind, NAN, box_float, box_integer, box_payload, is_float, is_infinite, is_integer,
+        is_nan, is_payload,
+    };
+    use alloc::string::ToString;
+    use core::cmp::Ordering;
+
+    fn rational(numerator: i64, denominator: i64) -> Float62 {
+        Float62::from_rational(numerator, denominator)
+    }
+
+    #[test]
+    fn rational_bits() {
+        for (numerator, denominator) in [
+            (1, 2),
+            (-1, 2),
+            (1, 3),
+            (-7, 3),
+            ((1 << 29) - 1, 2),
+            (-(1 << 29), 3),
+            (1, (1 << 29) - 1),
+        ] {
+            let number = box_rational(numerator, denominator);
+
+            assert!(is_rational(number));
+            assert!(!is_integer(number));
+            assert!(!is_float(number));
+            assert!(!is_payload(number));
+            assert!(!is_nan(number));
+            assert!(!is_infinite(number));
+            assert_eq!(unbox_rational(number), Some((numerator, denominator)));
+        }
+
+        assert_eq!(unbox_rational(box_integer(42)), None);
+        assert_eq!(unbox_rational(box_float(0.5)), None);
+        assert_eq!(unbox_rational(box_payload(42)), None);
+        assert_eq!(unbox_rational(NAN), None);
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(rational(2, 4).to_rational(), Some((1, 2)));
+        assert_eq!(rational(2, -4).to_rational(), Some((-1, 2)));
+        assert_eq!(rational(-2, -4).to_rational(), Some((1, 2)));
+        assert_eq!(rational(6, 3).to_integer(), Some(2));
+        assert_eq!(rational(0, 3).to_integer(), Some(0));
+        assert_eq!(rational(-6, -3).to_integer(), Some(2));
+    }
+
+    #[test]
+    fn fall_back_to_float() {
+        assert_eq!(
+            rational(1, 1 << 29).to_float(),
+            Some(1.0 / (1 << 29) as f64)
+        );
+        assert_eq!(
+            rational(1 << 29, 3).to_float(),
+            Some((1 << 29) as f64 / 3.0)
+        );
+        assert_eq!(rational(1, 0).to_float(), Some(f64::INFINITY));
+        assert_eq!(rational(-1, 0).to_float(), Some(f64::NEG_INFINITY));
+        assert!(rational(0, 0).is_nan());
+    }
+
+    #[test]
+    fn predicates() {
+        assert!(rational(1, 3).is_rational());
+        assert!(rational(1, 3).is_exact());
+        assert!(rational(1, 3).is_finite());
+        assert!(rational(1, 3).is_number());
+        assert!(!rational(1, 3).is_integer());
+        assert!(!rational(1, 3).is_float());
+        assert!(!rational(1, 3).is_nan());
+        assert!(Float62::from_integer(1).is_exact());
+        assert!(!Float62::from_float(0.5).is_exact());
+    }
+
+    #[test]
+    fn classify() {
+        assert_eq!(
+            rational(-1, 3).classify(),
+            Float62Kind::Rational {
+                numerator: -1,
+                denominator: 3
+            }
+        );
+        assert_eq!(
+            Float62::from(Float62Kind::Rational {
+                numerator: 2,
+                denominator: 6
+            })
+            .to_rational(),
+            Some((1, 3))
+        );
+    }
+
+    #[test]
+    fn div() {
+        assert_eq!(
+            (Float62::from_integer(1) / Float62::from_integer(3)).to_rational(),
+            Some((1, 3))
+        );
+        assert_eq!(
+            (Float62::from_integer(-4) / Float62::from_integer(6)).to_rational(),
+            Some((-2, 3))
+        );
+        assert_eq!(
+            (Float62::from_integer(6) / Float62::from_integer(3)).to_integer(),
+            Some(2)
+        );
+        assert_eq!(
+            (rational(1, 3) / rational(2, 3)).to_rational(),
+            Some((1, 2))
+        );
+        assert_eq!((rational(1, 3) / rational(1, 6)).to_integer(), Some(2));
+        assert_eq!(
+            (rational(1, 3) / Float62::from_integer(0)).to_float(),
+            Some(f64::INFINITY)
+        );
+        assert_eq!(
+            (Float62::from_integer(1) / Float62::from_integer(1 << 40)).to_float(),
+            Some(1.0 / (1u64 << 40) as f64)
+        );
+        assert!((Float62::from_integer(0) / Float62::from_integer(0)).is_nan());
+    }
+
+    #[test]
+    fn parse_string() {
+        assert_eq!(
+            "1/3".parse::<Float62>().unwrap().to_rational(),
+            Some((1, 3))
+        );
+        assert_eq!(
+            "-2/4".parse::<Float62>().unwrap().to_rational(),
+            Some((-1, 2))
+        );
+        assert_eq!("4/2".parse::<Float62>().unwrap().to_integer(), Some(2));
+        assert!("1/".parse::<Float62>().is_err());
+        assert!("1/x".parse::<Float62>().is_err());
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!(
+            (rational(1, 3) + rational(1, 6)).to_rational(),
+            Some((1, 2))
+        );
+        assert_eq!((rational(1, 3) + rational(2, 3)).to_integer(), Some(1));
+        assert_eq!(
+            (rational(1, 3) + Float62::from_integer(1)).to_rational(),
+            Some((4, 3))
+        );
+        assert_eq!(
+            (rational(1, 2) + Float62::from_float(0.25)).to_float(),
+            Some(0.75)
+        );
+        assert_eq!(
+            (rational(1, 2) + Float62::from_integer(1 << 40)).to_float(),
+            Some((1u64 << 40) as f64 + 0.5)
+        );
+    }
+
+    #[test]
+    fn sub() {
+        assert_eq!(
+            (rational(1, 2) - rational(1, 3)).to_rational(),
+            Some((1, 6))
+        );
+        assert_eq!(
+            (Float62::from_integer(1) - rational(1, 3)).to_rational(),
+            Some((2, 3))
+        );
+        assert_eq!((rational(1, 3) - rational(1, 3)).to_integer(), Some(0));
+    }
+
+    #[test]
+    fn mul() {
+        assert_eq!(
+            (rational(2, 3) * rational(3, 4)).to_rational(),
+            Some((1, 2))
+        );
+        assert_eq!(
+            (rational(1, 3) * Float62::from_integer(3)).to_integer(),
+            Some(1)
+        );
+        assert_eq!(
+            (rational(1, 3) * Float62::from_float(1.5)).to_float(),
+            Some(0.5)
+        );
+        assert_eq!(
+            (rational(1, (1 << 28) + 1) * rational(1, 3)).to_float(),
+            Some(1.0 / ((1u64 << 28) + 1) as f64 / 3.0)
+        );
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!((-rational(1, 3)).to_rational(), Some((-1, 3)));
+        assert_eq!((-rational(-1, 3)).to_rational(), Some((1, 3)));
+        assert_eq!(
+            (-rational(-(1 << 29), 3)).to_float(),
+            Some((1 << 29) as f64 / 3.0)
+        );
+    }
+
+    #[test]
+    fn rem() {
+        assert_eq!(
+            (rational(7, 2) % Float62::from_integer(2)).to_float(),
+            Some(1.5)
+        );
+    }
+
+    #[test]
+    fn cmp() {
+        assert_eq!(rational(1, 3), rational(2, 6));
+        assert_eq!(
+            rational(1, 3).partial_cmp(&rational(1, 2)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            rational(-1, 3).partial_cmp(&Float62::from_integer(0)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            Float62::from_integer(1).partial_cmp(&rational(2, 3)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(rational(1, 2), Float62::from_float(0.5));
+        assert_eq!(
+            rational(1, 3).partial_cmp(&Float62::from_float(0.5)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            rational(1, 3).partial_cmp(&Float62::from_float(f64::NAN)),
+            None
+        );
+    }
+
+    #[test]
+    fn canonical_bytes() {
+        assert!(Float62::try_from_bytes(box_rational(1, 2).to_le_bytes()).is_ok());
+        assert!(Float62::try_from_bytes(box_rational(2, 4).to_le_bytes()).is_err());
+        assert!(Float62::try_from_bytes(box_rational(2, 1).to_le_bytes()).is_err());
+        assert!(Float62::try_from_bytes(box_rational(1, 0).to_le_bytes()).is_err());
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(rational(1, 3).to_string(), "1/3");
+        assert_eq!(rational(-7, 2).to_string(), "-7/2");
+    }
+}
@@ -0,0 +1,331 @@
+#[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "avx2"))]
+mod avx2;
+
+use super::{Float62, Float62Tag};
+
+const MASK_WIDTH: usize = u64::BITS as _;
+
+/// Boxes 64-bit floating-point numbers.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+#[inline]
+pub fn box_floats(input: &[f64], output: &mut [Float62]) {
+    assert_eq!(input.len(), output.len());
+
+    #[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "avx2"))]
+    // SAFETY: AVX2 is enabled at compile time.
+    let (input, output) = unsafe { avx2::box_floats(input, output) };
+
+    for (x, y) in input.iter().zip(output) {
+        *y = Float62::from_float(*x);
+    }
+}
+
+/// Boxes integers.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+#[inline]
+pub fn box_integers(input: &[i64], output: &mut [Float62]) {
+    assert_eq!(input.len(), output.len());
+
+    #[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "avx2"))]
+    // SAFETY: AVX2 is enabled at compile time.
+    let (input, output) = unsafe { avx2::box_integers(input, output) };
+
+    for (x, y) in input.iter().zip(output) {
+        *y = Float62::from_integer(*x);
+    }
+}
+
+/// Unboxes integers, returning `true` if all numbers are integers.
+///
+/// Outputs for numbers other than integers are unspecified.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+#[inline]
+pub fn unbox_integers(input: &[Float62], output: &mut [i64]) -> bool {
+    assert_eq!(input.len(), output.len());
+
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64", target_feature = "avx2")))]
+    let mut integer = true;
+    #[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "avx2"))]
+    // SAFETY: AVX2 is enabled at compile time.
+    let (input, output, mut integer) = unsafe { avx2::unbox_integers(input, output) };
+
+    for (x, y) in input.iter().zip(output) {
+        // Avoid type assertions as outputs for non-integers are unspecified.
+        *y = x.to_bits() as i64 >> 1;
+        integer &= x.is_integer();
+    }
+
+    integer
+}
+
+/// Writes a bit mask of numbers with a tag.
+///
+/// The `i`-th bit of the `i / 64`-th word is set if the `i`-th number has the
+/// tag.
+///
+/// # Panics
+///
+/// Panics if the mask does not have exactly as many words as needed for the
+/// numbers.
+#[inline]
+pub fn classify_mask(input: &[Float62], tag: Float62Tag, mask: &mut [u64]) {
+    assert_eq!(input.len().div_ceil(MASK_WIDTH), mask.len());
+
+    #[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "avx2"))]
+    // SAFETY: AVX2 is enabled at compile time.
+    let (input, mask) = unsafe { avx2::classify_mask(input, tag, mask) };
+
+    for (numbers, word) in input.chunks(MASK_WIDTH).zip(mask) {
+        *word = numbers.iter().enumerate().fold(0, |word, (index, number)| {
+            word | (number.has_tag(tag) as u64) << index
+        });
+    }
+}
+
+/// Adds numbers element-wise.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+#[inline]
+pub fn add_slices(lhs: &[Float62], rhs: &[Float62], output: &mut [Float62]) {
+    assert_eq!(lhs.len(), rhs.len());
+    assert_eq!(lhs.len(), output.len());
+
+    #[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "avx2"))]
+    // SAFETY: AVX2 is enabled at compile time.
+    let (lhs, rhs, output) = unsafe { avx2::add_slices(lhs, rhs, output) };
+
+    for ((x, y), z) in lhs.iter().zip(rhs).zip(output) {
+        *z = *x + *y;
+    }
+}
+
+/// Subtracts numbers element-wise.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+#[inline]
+pub fn sub_slices(lhs: &[Float62], rhs: &[Float62], output: &mut [Float62]) {
+    assert_eq!(lhs.len(), rhs.len());
+    assert_eq!(lhs.len(), output.len());
+
+    #[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "avx2"))]
+    // SAFETY: AVX2 is enabled at compile time.
+    let (lhs, rhs, output) = unsafe { avx2::sub_slices(lhs, rhs, output) };
+
+    for ((x, y), z) in lhs.iter().zip(rhs).zip(output) {
+        *z = *x - *y;
+    }
+}
+
+/// Multiplies numbers element-wise.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+#[inline]
+pub fn mul_slices(lhs: &[Float62], rhs: &[Float62], output: &mut [Float62]) {
+    assert_eq!(lhs.len(), rhs.len());
+    assert_eq!(lhs.len(), output.len());
+
+    #[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "avx2"))]
+    // SAFETY: AVX2 is enabled at compile time.
+    let (lhs, rhs, output) = unsafe { avx2::mul_slices(lhs, rhs, output) };
+
+    for ((x, y), z) in lhs.iter().zip(rhs).zip(output) {
+        *z = *x * *y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{vec, vec::Vec};
+
+    fn numbers() -> Vec<Float62> {
+        let mut numbers = vec![
+            Float62::from_integer(0),
+            Float62::from_integer(1),
+            Float62::from_integer(-1),
+            Float62::from_integer(42),
+            Float62::from_integer((1 << 62) - 1),
+            Float62::from_integer(-(1 << 62)),
+            Float62::from_float(0.5),
+            Float62::from_float(-4.2),
+            Float62::from_float(f64::INFINITY),
+            Float62::from_float(f64::NAN),
+            Float62::from_payload(42),
+            Float62::NA,
+        ];
+
+        for index in 0..100 {
+            numbers.push(Float62::from_integer(index * 7 - 300));
+        }
+
+        numbers
+    }
+
+    #[test]
+    fn box_floats_matches_scalar() {
+        let input = [
+            0.0,
+            -0.0,
+            1.0,
+            -4.2,
+            1e-300,
+            1e300,
+            -1e300,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+            f64::MIN_POSITIVE,
+            f64::MAX,
+            3.0,
+        ];
+        let mut output = vec![Float62::default(); input.len()];
+
+        box_floats(&input, &mut output);
+
+        for (x, y) in input.iter().zip(&output) {
+            assert_eq!(y.to_bits(), Float62::from_float(*x).to_bits());
+        }
+    }
+
+    #[test]
+    fn box_integers_matches_scalar() {
+        let input = (-50..50).map(|x| x * 1_000_003).collect::<Vec<_>>();
+        let mut output = vec![Float62::default(); input.len()];
+
+        box_integers(&input, &mut output);
+
+        for (x, y) in input.iter().zip(&output) {
+            assert_eq!(y.to_integer(), Some(*x));
+        }
+    }
+
+    #[test]
+    fn unbox_integers_matches_scalar() {
+        let input = numbers();
+        let mut output = vec![0; input.len()];
+
+        assert!(!unbox_integers(&input, &mut output));
+
+        for (x, y) in input.iter().zip(&output) {
+            if let Some(x) = x.to_integer() {
+                assert_eq!(*y, x);
+            }
+        }
+
+        let input = &input[input.len() - 64..];
+        let mut output = vec![0; input.len()];
+
+        assert!(unbox_integers(input, &mut output));
+        assert!(
+            input
+                .iter()
+                .zip(&output)
+                .all(|(x, y)| x.to_integer() == Some(*y))
+        );
+    }
+
+    #[test]
+    fn classify_mask_matches_scalar() {
+        let input = numbers();
+        let mut mask = vec![0; input.len().div_ceil(MASK_WIDTH)];
+
+        for tag in [
+            Float62Tag::Integer,
+            Float62Tag::Float,
+            Float62Tag::Payload,
+            Float62Tag::Special,
+        ] {
+            classify_mask(&input, tag, &mut mask);
+
+            for (index, number) in input.iter().enumerate() {
+                assert_eq!(
+                    mask[index / MASK_WIDTH] >> (index % MASK_WIDTH) & 1 == 1,
+                    number.tag() == tag
+                );
+            }
+
+            assert_eq!(mask[mask.len() - 1] >> (input.len() % MASK_WIDTH), 0);
+        }
+    }
+
+    #[test]
+    fn operate_slices_matches_scalar() {
+        let lhs = numbers();
+        let rhs = lhs.iter().rev().copied().collect::<Vec<_>>();
+        let mut output = vec![Float62::default(); lhs.len()];
+
+        for (operate, scalar) in [
+            (
+                add_slices as fn(&[Float62], &[Float62], &mut [Float62]),
+                (|x, y| x + y) as fn(Float62, Float62) -> Float62,
+            ),
+            (sub_slices, |x, y| x - y),
+            (mul_slices, |x, y| x * y),
+        ] {
+            operate(&lhs, &rhs, &mut output);
+
+            for ((x, y), z) in lhs.iter().zip(&rhs).zip(&output) {
+                assert_eq!(z.to_bits(), scalar(*x, *y).to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn mul_slices_at_boundaries() {
+        // The first chunk fits in vector operations while the second does not.
+        let lhs = [
+            -(1 << 30),
+            -(1 << 30),
+            (1 << 30) - 1,
+            3,
+            1 << 30,
+            1 << 31,
+            -(1 << 30) - 1,
+            7,
+        ]
+        .map(Float62::from_integer);
+        let rhs = [
+            -(1 << 30),
+            (1 << 30) - 1,
+            (1 << 30) - 1,
+            -5,
+            1 << 30,
+            1 << 31,
+            1 << 32,
+            -7,
+        ]
+        .map(Float62::from_integer);
+        let mut output = [Float62::default(); 8];
+
+        mul_slices(&lhs, &rhs, &mut output);
+
+        for ((x, y), z) in lhs.iter().zip(&rhs).zip(&output) {
+            assert_eq!(z.to_bits(), (*x * *y).to_bits());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn reject_different_lengths() {
+        add_slices(
+            &[Float62::default(); 2],
+            &[Float62::default(); 3],
+            &mut [Float62::default(); 2],
+        );
+    }
+}
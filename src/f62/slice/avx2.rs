@@ -0,0 +1,300 @@
+use crate::f62::{
+    EXPONENT_MASK, Float62, Float62Tag, MANTISSA_WIDTH, MAXIMUM_EXPONENT, MINIMUM_EXPONENT, NAN,
+    NICHE, POSITIVE_INFINITY, ROTATION_COUNT, SIGN_MASK,
+};
+use core::arch::x86_64::{
+    __m256i, _mm256_add_epi64, _mm256_and_si256, _mm256_andnot_si256, _mm256_blendv_epi8,
+    _mm256_castsi256_pd, _mm256_cmpeq_epi64, _mm256_cmpgt_epi64, _mm256_loadu_si256,
+    _mm256_movemask_pd, _mm256_mul_epi32, _mm256_or_si256, _mm256_set1_epi64x,
+    _mm256_setzero_si256, _mm256_slli_epi64, _mm256_srli_epi64, _mm256_storeu_si256,
+    _mm256_sub_epi64, _mm256_testz_si256, _mm256_xor_si256,
+};
+
+const LANES: usize = 4;
+
+#[inline]
+#[target_feature(enable = "avx2")]
+fn splat(value: u64) -> __m256i {
+    _mm256_set1_epi64x(value as _)
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+fn select(condition: __m256i, then: __m256i, otherwise: __m256i) -> __m256i {
+    _mm256_blendv_epi8(otherwise, then, condition)
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+fn load<T: Copy>(values: &[T]) -> __m256i {
+    assert_eq!(size_of_val(values), size_of::<__m256i>());
+
+    // SAFETY: The slice has as many bytes as a vector.
+    unsafe { _mm256_loadu_si256(values.as_ptr().cast()) }
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+fn load_bits(numbers: &[Float62]) -> __m256i {
+    _mm256_xor_si256(load(numbers), splat(NICHE))
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+fn store_integers(values: &mut [i64], vector: __m256i) {
+    assert_eq!(values.len(), LANES);
+
+    // SAFETY: The slice has as many bytes as a vector.
+    unsafe { _mm256_storeu_si256(values.as_mut_ptr().cast(), vector) }
+}
+
+/// # Safety
+///
+/// No lane of the vector can be the reserved raw representation.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn store_bits(numbers: &mut [Float62], vector: __m256i) {
+    assert_eq!(numbers.len(), LANES);
+
+    // SAFETY: The slice has as many bytes as a vector and the caller guarantees
+    // that no lane is stored as zero.
+    unsafe {
+        _mm256_storeu_si256(
+            numbers.as_mut_ptr().cast(),
+            _mm256_xor_si256(vector, splat(NICHE)),
+        )
+    }
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+fn box_float(bits: __m256i) -> __m256i {
+    let exponent = _mm256_and_si256(
+        _mm256_srli_epi64::<{ MANTISSA_WIDTH as _ }>(bits),
+        splat(EXPONENT_MASK),
+    );
+    let special = select(
+        _mm256_cmpgt_epi64(
+            _mm256_andnot_si256(splat(SIGN_MASK), bits),
+            splat(f64::INFINITY.to_bits()),
+        ),
+        splat(NAN),
+        _mm256_add_epi64(
+            splat(POSITIVE_INFINITY),
+            _mm256_slli_epi64::<3>(_mm256_srli_epi64::<63>(bits)),
+        ),
+    );
+    let float = _mm256_or_si256(
+        _mm256_or_si256(
+            _mm256_slli_epi64::<{ ROTATION_COUNT as _ }>(bits),
+            _mm256_srli_epi64::<{ 64 - ROTATION_COUNT as i32 }>(bits),
+        ),
+        splat(0b11),
+    );
+
+    select(
+        _mm256_cmpgt_epi64(exponent, splat(MAXIMUM_EXPONENT)),
+        special,
+        _mm256_andnot_si256(_mm256_cmpgt_epi64(splat(MINIMUM_EXPONENT), exponent), float),
+    )
+}
+
+#[target_feature(enable = "avx2")]
+pub fn box_floats<'a, 'b>(
+    input: &'a [f64],
+    output: &'b mut [Float62],
+) -> (&'a [f64], &'b mut [Float62]) {
+    let mut inputs = input.chunks_exact(LANES);
+    let mut outputs = output.chunks_exact_mut(LANES);
+
+    for (x, y) in (&mut inputs).zip(&mut outputs) {
+        // SAFETY: Boxed floating-point numbers are never the reserved raw
+        // representation.
+        unsafe { store_bits(y, box_float(load(x))) }
+    }
+
+    (inputs.remainder(), outputs.into_remainder())
+}
+
+#[target_feature(enable = "avx2")]
+pub fn box_integers<'a, 'b>(
+    input: &'a [i64],
+    output: &'b mut [Float62],
+) -> (&'a [i64], &'b mut [Float62]) {
+    let mut inputs = input.chunks_exact(LANES);
+    let mut outputs = output.chunks_exact_mut(LANES);
+
+    for (x, y) in (&mut inputs).zip(&mut outputs) {
+        // SAFETY: Integers are never the reserved raw representation.
+        unsafe { store_bits(y, _mm256_slli_epi64::<1>(load(x))) }
+    }
+
+    (inputs.remainder(), outputs.into_remainder())
+}
+
+#[target_feature(enable = "avx2")]
+pub fn unbox_integers<'a, 'b>(
+    input: &'a [Float62],
+    output: &'b mut [i64],
+) -> (&'a [Float62], &'b mut [i64], bool) {
+    let mut inputs = input.chunks_exact(LANES);
+    let mut outputs = output.chunks_exact_mut(LANES);
+    let mut tags = _mm256_setzero_si256();
+
+    for (x, y) in (&mut inputs).zip(&mut outputs) {
+        let bits = load_bits(x);
+
+        tags = _mm256_or_si256(tags, bits);
+        // AVX2 has no arithmetic right shift of 64-bit integers.
+        store_integers(
+            y,
+            _mm256_or_si256(
+                _mm256_srli_epi64::<1>(bits),
+                _mm256_and_si256(bits, splat(SIGN_MASK)),
+            ),
+        );
+    }
+
+    (
+        inputs.remainder(),
+        outputs.into_remainder(),
+        _mm256_testz_si256(tags, splat(1)) == 1,
+    )
+}
+
+#[target_feature(enable = "avx2")]
+pub fn classify_mask<'a, 'b>(
+    input: &'a [Float62],
+    tag: Float62Tag,
+    mask: &'b mut [u64],
+) -> (&'a [Float62], &'b mut [u64]) {
+    let (tag_mask, value) = tag.mask();
+    // Compare stored representations directly instead of raw ones.
+    let (tag_mask, value) = (splat(tag_mask), splat(value ^ NICHE & tag_mask));
+    let mut inputs = input.chunks_exact(super::MASK_WIDTH);
+    let mut words = mask.iter_mut();
+
+    for (numbers, word) in (&mut inputs).zip(&mut words) {
+        *word = numbers
+            .chunks_exact(LANES)
+            .enumerate()
+            .fold(0, |word, (index, numbers)| {
+                let matched = _mm256_cmpeq_epi64(_mm256_and_si256(load(numbers), tag_mask), value);
+
+                word | (_mm256_movemask_pd(_mm256_castsi256_pd(matched)) as u64) << (index * LANES)
+            });
+    }
+
+    (inputs.remainder(), words.into_slice())
+}
+
+// Operates on integers in vectors and falls back to scalar operations for
+// chunks containing non-integers or overflowing integers.
+#[target_feature(enable = "avx2")]
+fn operate<'a, 'b>(
+    lhs: &'a [Float62],
+    rhs: &'a [Float62],
+    output: &'b mut [Float62],
+    operate: impl Fn(__m256i, __m256i) -> (__m256i, __m256i),
+    operate_scalar: impl Fn(Float62, Float62) -> Float62,
+) -> (&'a [Float62], &'a [Float62], &'b mut [Float62]) {
+    let mut lhs = lhs.chunks_exact(LANES);
+    let mut rhs = rhs.chunks_exact(LANES);
+    let mut outputs = output.chunks_exact_mut(LANES);
+
+    for ((xs, ys), zs) in (&mut lhs).zip(&mut rhs).zip(&mut outputs) {
+        let (x, y) = (load_bits(xs), load_bits(ys));
+        let (z, overflow) = operate(x, y);
+        let invalid = _mm256_or_si256(
+            _mm256_and_si256(_mm256_or_si256(x, y), splat(1)),
+            _mm256_and_si256(overflow, splat(SIGN_MASK)),
+        );
+
+        if _mm256_testz_si256(invalid, invalid) == 1 {
+            // SAFETY: Integers are never the reserved raw representation.
+            unsafe { store_bits(zs, z) }
+        } else {
+            for ((x, y), z) in xs.iter().zip(ys).zip(zs) {
+                *z = operate_scalar(*x, *y);
+            }
+        }
+    }
+
+    (lhs.remainder(), rhs.remainder(), outputs.into_remainder())
+}
+
+#[target_feature(enable = "avx2")]
+pub fn add_slices<'a, 'b>(
+    lhs: &'a [Float62],
+    rhs: &'a [Float62],
+    output: &'b mut [Float62],
+) -> (&'a [Float62], &'a [Float62], &'b mut [Float62]) {
+    operate(
+        lhs,
+        rhs,
+        output,
+        |x, y| {
+            let z = _mm256_add_epi64(x, y);
+
+            (
+                z,
+                _mm256_and_si256(_mm256_xor_si256(z, x), _mm256_xor_si256(z, y)),
+            )
+        },
+        |x, y| x + y,
+    )
+}
+
+#[target_feature(enable = "avx2")]
+pub fn sub_slices<'a, 'b>(
+    lhs: &'a [Float62],
+    rhs: &'a [Float62],
+    output: &'b mut [Float62],
+) -> (&'a [Float62], &'a [Float62], &'b mut [Float62]) {
+    operate(
+        lhs,
+        rhs,
+        output,
+        |x, y| {
+            let z = _mm256_sub_epi64(x, y);
+
+            (
+                z,
+                _mm256_and_si256(_mm256_xor_si256(x, y), _mm256_xor_si256(z, x)),
+            )
+        },
+        |x, y| x - y,
+    )
+}
+
+#[target_feature(enable = "avx2")]
+pub fn mul_slices<'a, 'b>(
+    lhs: &'a [Float62],
+    rhs: &'a [Float62],
+    output: &'b mut [Float62],
+) -> (&'a [Float62], &'a [Float62], &'b mut [Float62]) {
+    operate(
+        lhs,
+        rhs,
+        output,
+        |x, y| {
+            // AVX2 multiplies only 32-bit integers into 64-bit ones. So, we
+            // fall back to scalar operations unless both integers are in
+            // `[-2^30, 2^30)` and their product cannot overflow.
+            let offset = splat(1 << 31);
+            let z = _mm256_mul_epi32(_mm256_srli_epi64::<1>(x), _mm256_srli_epi64::<1>(y));
+
+            (
+                _mm256_slli_epi64::<1>(z),
+                _mm256_cmpgt_epi64(
+                    _mm256_srli_epi64::<32>(_mm256_or_si256(
+                        _mm256_add_epi64(x, offset),
+                        _mm256_add_epi64(y, offset),
+                    )),
+                    _mm256_setzero_si256(),
+                ),
+            )
+        },
+        |x, y| x * y,
+    )
+}
@@ -0,0 +1,376 @@
+use super::Float62;
+use crate::{f64::Float64, nan_box::NanBox};
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+const INTEGER_MAXIMUM: u64 = <Float64 as NanBox>::INTEGER_MAX as _;
+const PAYLOAD_LIMIT: u64 = 1 << <Float64 as NanBox>::PAYLOAD_WIDTH;
+
+// Converts a fraction of `numerator * 2^-exponent` into a floating-point
+// number. Numerators of rational and decimal numbers fit in mantissas of
+// 64-bit floating-point numbers.
+#[cfg(any(feature = "rational", feature = "decimal"))]
+const fn dyadic(numerator: i64, exponent: u32) -> Float64 {
+    Float64::from_float(numerator as f64 / (1u64 << exponent) as f64)
+}
+
+impl Float62 {
+    /// Transcodes this number into a number in the [`f64`](crate::f64)
+    /// encoding.
+    ///
+    /// Floating-point numbers, infinities, and NaN are mapped exactly.
+    /// Integers are boxed if they fit in [`Float64`] and converted into
+    /// floating-point numbers otherwise. Payloads are boxed if they fit in 49
+    /// bits. Although [`box_unsigned`](crate::f64::box_unsigned) boxes 50-bit
+    /// payloads, [`Float64`] reserves their most significant bit to tell them
+    /// from integers. So, payloads of 50 bits are lossy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this number cannot be represented exactly.
+    #[inline]
+    pub const fn to_f64_box(self) -> Result<Float64, TranscodingError> {
+        #[cfg(feature = "rational")]
+        if let Some((numerator, denominator)) = self.to_rational() {
+            return if (denominator as u64).is_power_of_two() {
+                Ok(dyadic(numerator, denominator.trailing_zeros()))
+            } else {
+                Err(TranscodingError)
+            };
+        }
+
+        #[cfg(feature = "decimal")]
+        if let Some((mantissa, scale)) = self.to_decimal() {
+            // A decimal number is exact only if its mantissa cancels out the
+            // factor of five in its denominator.
+            return match 5i64.checked_pow(scale) {
+                Some(power) if mantissa % power == 0 => Ok(dyadic(mantissa / power, scale)),
+                _ => Err(TranscodingError),
+            };
+        }
+
+        if let Some(integer) = self.to_integer() {
+            if integer.unsigned_abs() <= INTEGER_MAXIMUM || integer as f64 as i64 == integer {
+                Ok(Float64::from_integer(integer))
+            } else {
+                Err(TranscodingError)
+            }
+        } else if let Some(payload) = self.to_payload() {
+            if payload < PAYLOAD_LIMIT {
+                Ok(Float64::from_payload(payload))
+            } else {
+                Err(TranscodingError)
+            }
+        } else if let Some(number) = self.to_float() {
+            Ok(Float64::from_float(number))
+        } else {
+            Err(TranscodingError)
+        }
+    }
+
+    /// Transcodes a number in the [`f64`](crate::f64) encoding.
+    ///
+    /// Integers and payloads are always mapped exactly. Floating-point numbers
+    /// are mapped exactly unless they are out of the exponent range of 62-bit
+    /// floating-point numbers. Both zeros are mapped to the integer zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number cannot be represented exactly.
+    #[inline]
+    pub const fn from_f64_box(number: Float64) -> Result<Self, TranscodingError> {
+        if let Some(integer) = number.to_integer() {
+            Ok(Self::from_integer(integer))
+        } else if let Some(payload) = number.to_payload() {
+            Ok(Self::from_payload(payload))
+        } else {
+            let number = number.to_float_unchecked();
+            let boxed = Self::from_float(number);
+
+            match boxed.to_float() {
+                _ if number == 0.0 || number.is_nan() => Ok(boxed),
+                Some(unboxed) if unboxed.to_bits() == number.to_bits() => Ok(boxed),
+                _ => Err(TranscodingError),
+            }
+        }
+    }
+}
+
+/// Transcodes numbers into ones in the [`f64`](crate::f64) encoding.
+///
+/// Numbers which cannot be represented exactly are transcoded into NaN.
+///
+/// # Errors
+///
+/// Returns an error if any number cannot be represented exactly.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+#[inline]
+pub fn to_f64_boxes(input: &[Float62], output: &mut [Float64]) -> Result<(), TranscodingError> {
+    assert_eq!(input.len(), output.len());
+
+    let mut result = Ok(());
+
+    for (x, y) in input.iter().zip(output) {
+        *y = x.to_f64_box().unwrap_or_else(|error| {
+            result = Err(error);
+            Float64::from_float(f64::NAN)
+        });
+    }
+
+    result
+}
+
+/// Transcodes numbers in the [`f64`](crate::f64) encoding.
+///
+/// Numbers which cannot be represented exactly are transcoded as
+/// [`Float62::from_float`] does.
+///
+/// # Errors
+///
+/// Returns an error if any number cannot be represented exactly.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+#[inline]
+pub fn from_f64_boxes(input: &[Float64], output: &mut [Float62]) -> Result<(), TranscodingError> {
+    assert_eq!(input.len(), output.len());
+
+    let mut result = Ok(());
+
+    for (x, y) in input.iter().zip(output) {
+        *y = Float62::from_f64_box(*x).unwrap_or_else(|error| {
+            result = Err(error);
+            Float62::from_float(x.to_float_unchecked())
+        });
+    }
+
+    result
+}
+
+/// An error of lossy transcoding between the `f62` and `f64` encodings.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TranscodingError;
+
+impl Display for TranscodingError {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(formatter, "lossy transcoding")
+    }
+}
+
+impl Error for TranscodingError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::ToString, vec};
+
+    fn transcode(number: Float62) -> Result<Float62, TranscodingError> {
+        Float62::from_f64_box(number.to_f64_box()?)
+    }
+
+    #[test]
+    fn integer() {
+        for integer in [0, 1, -1, 42, -(1 << 49) + 1, (1 << 49) - 1] {
+            let number = Float62::from_integer(integer).to_f64_box().unwrap();
+
+            assert_eq!(number.to_integer(), Some(integer));
+            assert_eq!(
+                transcode(Float62::from_integer(integer)),
+                Ok(Float62::from_integer(integer))
+            );
+        }
+    }
+
+    #[test]
+    fn integer_out_of_range() {
+        for integer in [1 << 49, -(1 << 53), 1 << 60, -(1 << 62)] {
+            assert_eq!(
+                Float62::from_integer(integer)
+                    .to_f64_box()
+                    .unwrap()
+                    .to_float(),
+                Some(integer as f64)
+            );
+        }
+
+        for integer in [(1 << 53) + 1, (1 << 62) - 1] {
+            assert_eq!(
+                Float62::from_integer(integer).to_f64_box(),
+                Err(TranscodingError)
+            );
+        }
+    }
+
+    #[test]
+    fn payload() {
+        for payload in [0, 42, PAYLOAD_LIMIT - 1] {
+            assert_eq!(
+                Float62::from_payload(payload)
+                    .to_f64_box()
+                    .unwrap()
+                    .to_payload(),
+                Some(payload)
+            );
+            assert_eq!(
+                transcode(Float62::from_payload(payload)).map(Float62::to_bits),
+                Ok(Float62::from_payload(payload).to_bits())
+            );
+        }
+
+        for payload in [PAYLOAD_LIMIT, (1 << 50) - 1] {
+            assert_eq!(
+                Float62::from_payload(payload).to_f64_box(),
+                Err(TranscodingError)
+            );
+        }
+    }
+
+    #[test]
+    fn float() {
+        for number in [4.2, -4.2, 1e70, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(
+                Float62::from_float(number)
+                    .to_f64_box()
+                    .unwrap()
+                    .to_float()
+                    .map(f64::to_bits),
+                Some(number.to_bits())
+            );
+            assert_eq!(
+                transcode(Float62::from_float(number)).map(Float62::to_bits),
+                Ok(Float62::from_float(number).to_bits())
+            );
+        }
+
+        assert!(transcode(Float62::from_float(f64::NAN)).unwrap().is_nan());
+    }
+
+    #[test]
+    fn zero() {
+        for number in [0.0, -0.0] {
+            assert_eq!(
+                Float62::from_f64_box(Float64::from_float(number)),
+                Ok(Float62::from_integer(0))
+            );
+        }
+    }
+
+    #[test]
+    fn float_out_of_range() {
+        for number in [1e300, -1e-300, f64::MAX, f64::MIN_POSITIVE] {
+            assert_eq!(
+                Float62::from_f64_box(Float64::from_float(number)),
+                Err(TranscodingError)
+            );
+        }
+    }
+
+    #[test]
+    fn na() {
+        assert_eq!(Float62::NA.to_f64_box(), Err(TranscodingError));
+    }
+
+    #[cfg(feature = "rational")]
+    #[test]
+    fn rational() {
+        assert_eq!(
+            Float62::from_rational(3, 4)
+                .to_f64_box()
+                .unwrap()
+                .to_float(),
+            Some(0.75)
+        );
+        assert_eq!(
+            Float62::from_rational(-1, 1 << 20)
+                .to_f64_box()
+                .unwrap()
+                .to_float(),
+            Some(-1.0 / (1 << 20) as f64)
+        );
+        assert_eq!(
+            Float62::from_rational(1, 3).to_f64_box(),
+            Err(TranscodingError)
+        );
+        assert_eq!(
+            Float62::from_rational(5, 6).to_f64_box(),
+            Err(TranscodingError)
+        );
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn decimal() {
+        assert_eq!(
+            Float62::from_decimal(25, 2)
+                .to_f64_box()
+                .unwrap()
+                .to_float(),
+            Some(0.25)
+        );
+        assert_eq!(
+            Float62::from_decimal(-375, 3)
+                .to_f64_box()
+                .unwrap()
+                .to_float(),
+            Some(-0.375)
+        );
+        assert_eq!(
+            Float62::from_decimal(1, 1).to_f64_box(),
+            Err(TranscodingError)
+        );
+    }
+
+    #[test]
+    fn slices() {
+        let numbers = [
+            Float62::from_integer(42),
+            Float62::from_float(4.2),
+            Float62::from_payload(42),
+            Float62::from_float(f64::NEG_INFINITY),
+        ];
+        let mut boxes = [Float64::default(); 4];
+        let mut output = [Float62::default(); 4];
+
+        assert_eq!(to_f64_boxes(&numbers, &mut boxes), Ok(()));
+        assert_eq!(from_f64_boxes(&boxes, &mut output), Ok(()));
+
+        for (x, y) in numbers.iter().zip(&output) {
+            assert_eq!(x.to_bits(), y.to_bits());
+        }
+    }
+
+    #[test]
+    fn lossy_slices() {
+        let mut boxes = vec![Float64::default(); 2];
+        let mut output = vec![Float62::default(); 2];
+
+        assert_eq!(
+            to_f64_boxes(&[Float62::from_integer(1), Float62::NA], &mut boxes),
+            Err(TranscodingError)
+        );
+        assert_eq!(boxes[0].to_integer(), Some(1));
+        assert!(boxes[1].is_nan());
+
+        assert_eq!(
+            from_f64_boxes(
+                &[Float64::from_integer(1), Float64::from_float(1e300)],
+                &mut output
+            ),
+            Err(TranscodingError)
+        );
+        assert_eq!(output[0], Float62::from_integer(1));
+        assert!(output[1].is_infinite());
+    }
+
+    #[test]
+    fn display_error() {
+        assert_eq!(TranscodingError.to_string(), "lossy transcoding");
+    }
+}
@@ -0,0 +1,522 @@
+//! Vectors of 62-bit floating-point numbers with bit masks of tags.
+//!
+//! Each tag has a bit mask whose `i`-th bit is set if the `i`-th number has
+//! the tag, so that numbers of a tag can be found and counted without
+//! decoding all numbers.
+
+use super::{Float62, Float62Tag, box_floats, box_integers, classify_mask, unbox_integers};
+use alloc::{vec, vec::Vec};
+
+const MASK_WIDTH: usize = u64::BITS as _;
+const TAGS: [Float62Tag; 4] = [
+    Float62Tag::Integer,
+    Float62Tag::Float,
+    Float62Tag::Payload,
+    Float62Tag::Special,
+];
+
+/// A vector of 62-bit floating-point numbers.
+///
+/// It keeps a bit mask of numbers for each tag up to date as it is mutated.
+///
+/// Vectors are equal if they have the same raw representations of numbers.
+/// So, a vector containing NaN is equal to itself.
+#[derive(Clone, Debug, Default)]
+pub struct Float62Vec {
+    numbers: Vec<Float62>,
+    masks: [Vec<u64>; TAGS.len()],
+    counts: [usize; TAGS.len()],
+}
+
+impl Float62Vec {
+    /// Creates an empty vector.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            numbers: Vec::new(),
+            masks: [const { Vec::new() }; TAGS.len()],
+            counts: [0; TAGS.len()],
+        }
+    }
+
+    /// Creates an empty vector with a capacity.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mask = || Vec::with_capacity(capacity.div_ceil(MASK_WIDTH));
+
+        Self {
+            numbers: Vec::with_capacity(capacity),
+            masks: [mask(), mask(), mask(), mask()],
+            counts: [0; TAGS.len()],
+        }
+    }
+
+    fn from_numbers(numbers: Vec<Float62>) -> Self {
+        let mask = |tag| {
+            let mut mask = vec![0; numbers.len().div_ceil(MASK_WIDTH)];
+            classify_mask(&numbers, tag, &mut mask);
+            mask
+        };
+        let masks = TAGS.map(mask);
+
+        Self {
+            counts: TAGS.map(|tag| count(&masks[tag as usize])),
+            masks,
+            numbers,
+        }
+    }
+
+    /// Returns a number of elements.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.numbers.len()
+    }
+
+    /// Returns `true` if this vector has no element.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.numbers.is_empty()
+    }
+
+    /// Returns a slice of elements.
+    #[inline]
+    pub const fn as_slice(&self) -> &[Float62] {
+        self.numbers.as_slice()
+    }
+
+    /// Returns an element at an index.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<Float62> {
+        self.numbers.get(index).copied()
+    }
+
+    /// Returns an iterator of elements.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Float62> + '_ {
+        self.numbers.iter().copied()
+    }
+
+    /// Returns a bit mask of elements with a tag.
+    ///
+    /// The `i`-th bit of the `i / 64`-th word is set if the `i`-th element has
+    /// the tag.
+    #[inline]
+    pub fn mask(&self, tag: Float62Tag) -> &[u64] {
+        &self.masks[tag as usize]
+    }
+
+    /// Returns a number of elements with a tag.
+    #[inline]
+    pub const fn count(&self, tag: Float62Tag) -> usize {
+        self.counts[tag as usize]
+    }
+
+    /// Returns `true` if all elements are integers.
+    #[inline]
+    pub const fn is_all_integer(&self) -> bool {
+        self.count(Float62Tag::Integer) == self.len()
+    }
+
+    /// Returns `true` if all elements are finite non-integer floating-point
+    /// numbers.
+    #[inline]
+    pub const fn is_all_float(&self) -> bool {
+        self.count(Float62Tag::Float) == self.len()
+    }
+
+    /// Returns an iterator of indices of elements with a tag.
+    #[inline]
+    pub fn indices(&self, tag: Float62Tag) -> impl Iterator<Item = usize> + '_ {
+        self.mask(tag).iter().enumerate().flat_map(|(index, word)| {
+            let mut word = *word;
+
+            core::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+
+                let offset = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(index * MASK_WIDTH + offset)
+            })
+        })
+    }
+
+    /// Returns an iterator of integers.
+    #[inline]
+    pub fn integers(&self) -> impl Iterator<Item = i64> + '_ {
+        self.indices(Float62Tag::Integer)
+            .map(|index| self.numbers[index].to_integer_unchecked())
+    }
+
+    /// Returns an iterator of finite non-integer floating-point numbers.
+    #[inline]
+    pub fn floats(&self) -> impl Iterator<Item = f64> + '_ {
+        self.indices(Float62Tag::Float)
+            .map(|index| self.numbers[index].to_float_unchecked())
+    }
+
+    /// Appends an element.
+    #[inline]
+    pub fn push(&mut self, number: Float62) {
+        let index = self.len();
+
+        if index.is_multiple_of(MASK_WIDTH) {
+            for mask in &mut self.masks {
+                mask.push(0);
+            }
+        }
+
+        self.numbers.push(number);
+        self.mark(index, number.tag(), true);
+    }
+
+    /// Removes the last element.
+    #[inline]
+    pub fn pop(&mut self) -> Option<Float62> {
+        let number = self.numbers.pop()?;
+        let index = self.len();
+
+        self.mark(index, number.tag(), false);
+
+        if index.is_multiple_of(MASK_WIDTH) {
+            for mask in &mut self.masks {
+                mask.pop();
+            }
+        }
+
+        Some(number)
+    }
+
+    /// Replaces an element at an index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    #[inline]
+    pub fn set(&mut self, index: usize, number: Float62) {
+        let tag = self.numbers[index].tag();
+
+        self.mark(index, tag, false);
+        self.numbers[index] = number;
+        self.mark(index, number.tag(), true);
+    }
+
+    /// Removes all elements.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.numbers.clear();
+
+        for mask in &mut self.masks {
+            mask.clear();
+        }
+
+        self.counts = [0; TAGS.len()];
+    }
+
+    fn mark(&mut self, index: usize, tag: Float62Tag, value: bool) {
+        let word = &mut self.masks[tag as usize][index / MASK_WIDTH];
+        let bit = 1 << (index % MASK_WIDTH);
+        let count = &mut self.counts[tag as usize];
+
+        if value {
+            *word |= bit;
+            *count += 1;
+        } else {
+            *word &= !bit;
+            *count -= 1;
+        }
+    }
+
+    /// Converts elements into integers if all of them are integers.
+    #[inline]
+    pub fn to_integers(&self) -> Option<Vec<i64>> {
+        if !self.is_all_integer() {
+            return None;
+        }
+
+        let mut integers = vec![0; self.len()];
+        unbox_integers(&self.numbers, &mut integers);
+        Some(integers)
+    }
+
+    /// Converts elements into 64-bit floating-point numbers if none of them is
+    /// a payload or a missing value.
+    #[inline]
+    pub fn to_floats(&self) -> Option<Vec<f64>> {
+        if self.is_all_float() {
+            Some(self.iter().map(Float62::to_float_unchecked).collect())
+        } else if self.count(Float62Tag::Payload) > 0 {
+            None
+        } else {
+            self.iter()
+                .map(|number| {
+                    if number.is_na() {
+                        None
+                    } else {
+                        Some(
+                            number
+                                .to_number()
+                                .map_or_else(|float| float, |integer| integer as _),
+                        )
+                    }
+                })
+                .collect()
+        }
+    }
+}
+
+fn count(mask: &[u64]) -> usize {
+    mask.iter().map(|word| word.count_ones() as usize).sum()
+}
+
+impl PartialEq for Float62Vec {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.counts == other.counts
+            && self.masks == other.masks
+            && self.numbers.len() == other.numbers.len()
+            && self
+                .numbers
+                .iter()
+                .zip(&other.numbers)
+                .all(|(x, y)| x.to_bits() == y.to_bits())
+    }
+}
+
+impl Eq for Float62Vec {}
+
+/// Integers out of the range of 63 bits wrap around as in
+/// [`Float62::from_integer`].
+impl From<Vec<i64>> for Float62Vec {
+    #[inline]
+    fn from(integers: Vec<i64>) -> Self {
+        let mut numbers = vec![Float62::default(); integers.len()];
+        box_integers(&integers, &mut numbers);
+        Self::from_numbers(numbers)
+    }
+}
+
+impl From<Vec<f64>> for Float62Vec {
+    #[inline]
+    fn from(floats: Vec<f64>) -> Self {
+        let mut numbers = vec![Float62::default(); floats.len()];
+        box_floats(&floats, &mut numbers);
+        Self::from_numbers(numbers)
+    }
+}
+
+impl From<Vec<Float62>> for Float62Vec {
+    #[inline]
+    fn from(numbers: Vec<Float62>) -> Self {
+        Self::from_numbers(numbers)
+    }
+}
+
+impl From<Float62Vec> for Vec<Float62> {
+    #[inline]
+    fn from(vector: Float62Vec) -> Self {
+        vector.numbers
+    }
+}
+
+impl FromIterator<Float62> for Float62Vec {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = Float62>>(iterator: T) -> Self {
+        Self::from_numbers(iterator.into_iter().collect())
+    }
+}
+
+impl Extend<Float62> for Float62Vec {
+    #[inline]
+    fn extend<T: IntoIterator<Item = Float62>>(&mut self, iterator: T) {
+        for number in iterator {
+            self.push(number);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_masks(vector: &Float62Vec) {
+        let expected = Float62Vec::from(vector.as_slice().to_vec());
+
+        assert_eq!(vector.masks, expected.masks);
+        assert_eq!(vector.counts, expected.counts);
+
+        for tag in TAGS {
+            assert_eq!(
+                vector.indices(tag).collect::<Vec<_>>(),
+                (0..vector.len())
+                    .filter(|index| vector.as_slice()[*index].tag() == tag)
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    fn numbers() -> impl Iterator<Item = Float62> {
+        (0..200).map(|index| match index % 5 {
+            0 | 1 => Float62::from_integer(index),
+            2 => Float62::from_float(index as f64 + 0.5),
+            3 => Float62::from_payload(index as _),
+            _ => Float62::from_float(f64::INFINITY),
+        })
+    }
+
+    #[test]
+    fn new() {
+        let vector = Float62Vec::new();
+
+        assert!(vector.is_empty());
+        assert!(vector.is_all_integer());
+        assert!(vector.is_all_float());
+        assert_eq!(vector, Float62Vec::default());
+        assert_eq!(vector, Float62Vec::with_capacity(42));
+    }
+
+    #[test]
+    fn push_and_pop() {
+        let mut vector = Float62Vec::new();
+
+        for (index, number) in numbers().enumerate() {
+            vector.push(number);
+
+            assert_eq!(vector.len(), index + 1);
+            assert_masks(&vector);
+        }
+
+        for number in numbers().collect::<Vec<_>>().into_iter().rev() {
+            assert_eq!(vector.pop().map(Float62::to_bits), Some(number.to_bits()));
+            assert_masks(&vector);
+        }
+
+        assert_eq!(vector.pop(), None);
+        assert_eq!(vector, Float62Vec::new());
+    }
+
+    #[test]
+    fn set() {
+        let mut vector = numbers().collect::<Float62Vec>();
+
+        for index in 0..vector.len() {
+            vector.set(index, Float62::from_integer(index as _));
+            assert_masks(&vector);
+        }
+
+        assert!(vector.is_all_integer());
+        assert_eq!(vector.count(Float62Tag::Integer), 200);
+    }
+
+    #[test]
+    fn eq() {
+        let vector = numbers()
+            .chain([Float62::from_float(f64::NAN), Float62::NA])
+            .collect::<Float62Vec>();
+
+        assert_eq!(vector, vector.clone());
+        assert_ne!(vector, numbers().collect());
+    }
+
+    #[test]
+    fn from_wrapping_integers() {
+        let vector = Float62Vec::from(vec![i64::MAX, i64::MIN]);
+
+        assert_eq!(vector.as_slice()[0].to_integer(), Some(-1));
+        assert_eq!(vector.as_slice()[1].to_integer(), Some(0));
+        assert_eq!(
+            vector,
+            Float62Vec::from(vec![
+                Float62::from_integer(i64::MAX),
+                Float62::from_integer(i64::MIN)
+            ])
+        );
+    }
+
+    #[test]
+    fn clear() {
+        let mut vector = numbers().collect::<Float62Vec>();
+
+        vector.clear();
+
+        assert_eq!(vector, Float62Vec::new());
+    }
+
+    #[test]
+    fn extend() {
+        let mut vector = Float62Vec::new();
+
+        vector.extend(numbers());
+
+        assert_masks(&vector);
+    }
+
+    #[test]
+    fn iterate() {
+        let vector = numbers().collect::<Float62Vec>();
+
+        assert_eq!(
+            vector.integers().collect::<Vec<_>>(),
+            numbers()
+                .filter_map(Float62::to_integer)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vector.floats().collect::<Vec<_>>(),
+            numbers()
+                .filter(|number| number.is_float())
+                .map(Float62::to_float_unchecked)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(vector.count(Float62Tag::Payload), 40);
+        assert_eq!(vector.count(Float62Tag::Special), 40);
+    }
+
+    #[test]
+    fn convert_integers() {
+        let integers = (-100..100).collect::<Vec<_>>();
+        let vector = Float62Vec::from(integers.clone());
+
+        assert!(vector.is_all_integer());
+        assert!(!vector.is_all_float());
+        assert_eq!(vector.to_integers(), Some(integers.clone()));
+        assert_eq!(
+            vector.to_floats(),
+            Some(integers.iter().map(|x| *x as f64).collect())
+        );
+        assert_masks(&vector);
+    }
+
+    #[test]
+    fn convert_floats() {
+        let floats = (-100..100).map(|x| x as f64 + 0.25).collect::<Vec<_>>();
+        let vector = Float62Vec::from(floats.clone());
+
+        assert!(vector.is_all_float());
+        assert!(!vector.is_all_integer());
+        assert_eq!(vector.to_floats(), Some(floats));
+        assert_eq!(vector.to_integers(), None);
+        assert_masks(&vector);
+    }
+
+    #[test]
+    fn convert_mixed_numbers() {
+        let vector = Float62Vec::from(vec![
+            Float62::from_integer(1),
+            Float62::from_float(0.5),
+            Float62::from_float(f64::NEG_INFINITY),
+        ]);
+
+        assert_eq!(vector.to_floats(), Some(vec![1.0, 0.5, f64::NEG_INFINITY]));
+        assert_eq!(vector.to_integers(), None);
+        assert_eq!(
+            Float62Vec::from(vec![Float62::from_integer(1), Float62::NA]).to_floats(),
+            None
+        );
+        assert_eq!(
+            Float62Vec::from(vec![Float62::from_integer(1), Float62::from_payload(1)]).to_floats(),
+            None
+        );
+    }
+}
@@ -1,10 +1,20 @@
 //! NaN boxing for `f64`.
 
+use crate::nan_box::{Kind, NanBox};
+use core::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+};
+
 const EXPONENT_MASK_OFFSET: usize = 48;
 const SIGN_MASK: u64 = 1 << 63;
 const EXPONENT_MASK: u64 = 0x7ffc << EXPONENT_MASK_OFFSET;
 const PAYLOAD_MASK: u64 = !(0xfffc << EXPONENT_MASK_OFFSET);
 
+const PAYLOAD_TAG: u64 = 1 << 49;
+const INTEGER_MAXIMUM: i64 = (1 << 49) - 1;
+
 /// Boxes a 50-bit unsigned integer.
 #[inline]
 pub const fn box_unsigned(payload: u64) -> u64 {
@@ -49,6 +59,332 @@ pub const fn is_boxed(number: u64) -> bool {
     number & EXPONENT_MASK == EXPONENT_MASK
 }
 
+/// A 64-bit floating-point number with 50-bit signed integers and 49-bit
+/// payloads boxed in its NaN space.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct Float64(u64);
+
+impl Float64 {
+    /// Creates a 64-bit floating-point number from its raw representation.
+    #[inline]
+    pub const fn from_bits(number: u64) -> Self {
+        Self(number)
+    }
+
+    /// Returns a raw representation.
+    #[inline]
+    pub const fn to_bits(self) -> u64 {
+        self.0
+    }
+
+    /// Creates a 64-bit floating-point number from a 49-bit payload.
+    #[inline]
+    pub const fn from_payload(payload: u64) -> Self {
+        Self(box_unsigned(PAYLOAD_TAG | payload & (PAYLOAD_TAG - 1)))
+    }
+
+    /// Creates a 64-bit floating-point number from a 50-bit signed integer.
+    ///
+    /// An integer out of the range is converted into a floating-point number.
+    #[inline]
+    pub const fn from_integer(integer: i64) -> Self {
+        if integer.unsigned_abs() <= INTEGER_MAXIMUM as u64 {
+            Self(box_signed(integer))
+        } else {
+            Self::from_float(integer as f64)
+        }
+    }
+
+    /// Creates a 64-bit floating-point number from a primitive one.
+    #[inline]
+    pub const fn from_float(number: f64) -> Self {
+        Self(if number.is_nan() { f64::NAN } else { number }.to_bits())
+    }
+
+    /// Returns a payload.
+    #[inline]
+    pub const fn to_payload(self) -> Option<u64> {
+        if self.is_payload() {
+            Some(self.to_payload_unchecked())
+        } else {
+            None
+        }
+    }
+
+    /// Returns a payload without any type check.
+    #[inline]
+    pub const fn to_payload_unchecked(self) -> u64 {
+        unbox_unsigned_unchecked(self.0) & !PAYLOAD_TAG
+    }
+
+    /// Returns an integer.
+    #[inline]
+    pub const fn to_integer(self) -> Option<i64> {
+        if self.is_integer() {
+            Some(self.to_integer_unchecked())
+        } else {
+            None
+        }
+    }
+
+    /// Returns an integer without any type check.
+    #[inline]
+    pub const fn to_integer_unchecked(self) -> i64 {
+        (if self.0 & SIGN_MASK == 0 { 1 } else { -1 }) * unbox_unsigned_unchecked(self.0) as i64
+    }
+
+    /// Returns a primitive floating-point number.
+    #[inline]
+    pub const fn to_float(self) -> Option<f64> {
+        if is_boxed(self.0) {
+            None
+        } else {
+            Some(self.to_float_unchecked())
+        }
+    }
+
+    /// Returns a primitive floating-point number without any type check.
+    #[inline]
+    pub const fn to_float_unchecked(self) -> f64 {
+        f64::from_bits(self.0)
+    }
+
+    /// Returns `true` if this number is an integer.
+    #[inline]
+    pub const fn is_integer(self) -> bool {
+        is_boxed(self.0) && self.0 & PAYLOAD_TAG == 0
+    }
+
+    /// Returns `true` if this number is a payload.
+    #[inline]
+    pub const fn is_payload(self) -> bool {
+        is_boxed(self.0) && self.0 & PAYLOAD_TAG != 0
+    }
+
+    /// Returns `true` if this number is an infinity.
+    #[inline]
+    pub const fn is_infinite(self) -> bool {
+        !is_boxed(self.0) && self.to_float_unchecked().is_infinite()
+    }
+
+    /// Returns `true` if this number is NaN.
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        !is_boxed(self.0) && self.to_float_unchecked().is_nan()
+    }
+
+    #[inline]
+    const fn to_number(self) -> Result<i64, f64> {
+        if let Some(integer) = self.to_integer() {
+            Ok(integer)
+        } else if let Some(float) = self.to_float() {
+            Err(float)
+        } else {
+            Err(f64::NAN)
+        }
+    }
+
+    #[inline]
+    const fn to_number_float(self) -> f64 {
+        match self.to_number() {
+            Ok(integer) => integer as f64,
+            Err(float) => float,
+        }
+    }
+}
+
+fn operate_float(lhs: Float64, rhs: Float64, operate: fn(f64, f64) -> f64) -> Float64 {
+    Float64::from_float(operate(lhs.to_number_float(), rhs.to_number_float()))
+}
+
+macro_rules! operate {
+    ($lhs:ident, $rhs:ident, $operate:ident, $checked_operate:ident) => {{
+        let (Some(x), Some(y)) = ($lhs.to_integer(), $rhs.to_integer()) else {
+            return operate_float($lhs, $rhs, f64::$operate);
+        };
+
+        x.$checked_operate(y).map_or_else(
+            || operate_float($lhs, $rhs, f64::$operate),
+            Self::from_integer,
+        )
+    }};
+}
+
+impl Default for Float64 {
+    #[inline]
+    fn default() -> Self {
+        Self::from_integer(0)
+    }
+}
+
+impl Add for Float64 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        operate!(self, rhs, add, checked_add)
+    }
+}
+
+impl Sub for Float64 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        operate!(self, rhs, sub, checked_sub)
+    }
+}
+
+impl Mul for Float64 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        operate!(self, rhs, mul, checked_mul)
+    }
+}
+
+impl Div for Float64 {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        let (Some(x), Some(y)) = (self.to_integer(), rhs.to_integer()) else {
+            return operate_float(self, rhs, f64::div);
+        };
+
+        if y != 0 && x % y == 0 {
+            Self::from_integer(x / y)
+        } else {
+            Self::from_float(x as f64 / y as f64)
+        }
+    }
+}
+
+impl Rem for Float64 {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, rhs: Self) -> Self::Output {
+        let (Some(x), Some(y)) = (self.to_integer(), rhs.to_integer()) else {
+            return operate_float(self, rhs, f64::rem);
+        };
+
+        if y == 0 {
+            Self::from_float(f64::NAN)
+        } else {
+            Self::from_integer(x % y)
+        }
+    }
+}
+
+impl AddAssign for Float64 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Float64 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Float64 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Float64 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Neg for Float64 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        match self.to_number() {
+            Ok(x) => Self::from_integer(-x),
+            Err(x) => Self::from_float(-x),
+        }
+    }
+}
+
+impl Display for Float64 {
+    #[inline]
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(integer) = self.to_integer() {
+            write!(formatter, "{integer}")
+        } else if let Some(float) = self.to_float() {
+            write!(formatter, "{float}")
+        } else {
+            write!(formatter, "0x{:x}", self.to_payload_unchecked())
+        }
+    }
+}
+
+impl PartialEq for Float64 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Float64 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.0 == other.0 {
+            return (!self.is_nan()).then_some(Ordering::Equal);
+        }
+
+        match (self.to_number(), other.to_number()) {
+            (Ok(x), Ok(y)) => x.partial_cmp(&y),
+            _ => self.to_number_float().partial_cmp(&other.to_number_float()),
+        }
+    }
+}
+
+impl NanBox for Float64 {
+    const PAYLOAD_WIDTH: u32 = 49;
+    const INTEGER_MIN: i64 = -INTEGER_MAXIMUM;
+    const INTEGER_MAX: i64 = INTEGER_MAXIMUM;
+
+    #[inline]
+    fn from_integer(integer: i64) -> Self {
+        Self::from_integer(integer)
+    }
+
+    #[inline]
+    fn from_float(number: f64) -> Self {
+        Self::from_float(number)
+    }
+
+    #[inline]
+    fn from_payload(payload: u64) -> Self {
+        Self::from_payload(payload)
+    }
+
+    #[inline]
+    fn classify(self) -> Kind {
+        if self.is_integer() {
+            Kind::Integer(self.to_integer_unchecked())
+        } else if self.is_payload() {
+            Kind::Payload(self.to_payload_unchecked())
+        } else {
+            Kind::Float(self.to_float_unchecked())
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,4 +483,134 @@ mod tests {
         assert_eq!(unbox_from_f64(1.0), None);
         assert_eq!(unbox_from_f64(42.0), None);
     }
+
+    mod float64 {
+        use super::*;
+        use alloc::string::ToString;
+
+        #[test]
+        fn integer() {
+            assert_eq!(Float64::from_integer(0).to_integer(), Some(0));
+            assert_eq!(Float64::from_integer(42).to_integer(), Some(42));
+            assert_eq!(Float64::from_integer(-42).to_integer(), Some(-42));
+            assert_eq!(
+                Float64::from_integer(INTEGER_MAXIMUM).to_integer(),
+                Some(INTEGER_MAXIMUM)
+            );
+            assert_eq!(
+                Float64::from_integer(-INTEGER_MAXIMUM).to_integer(),
+                Some(-INTEGER_MAXIMUM)
+            );
+            assert_eq!(Float64::from_integer(42).to_float(), None);
+            assert_eq!(Float64::from_integer(42).to_payload(), None);
+        }
+
+        #[test]
+        fn convert_out_of_range_integer_to_float() {
+            assert_eq!(
+                Float64::from_integer(INTEGER_MAXIMUM + 1).to_float(),
+                Some((INTEGER_MAXIMUM + 1) as f64)
+            );
+            assert_eq!(
+                Float64::from_integer(i64::MIN).to_float(),
+                Some(i64::MIN as f64)
+            );
+        }
+
+        #[test]
+        fn payload() {
+            assert_eq!(Float64::from_payload(0).to_payload(), Some(0));
+            assert_eq!(Float64::from_payload(42).to_payload(), Some(42));
+            assert_eq!(
+                Float64::from_payload(PAYLOAD_TAG - 1).to_payload(),
+                Some(PAYLOAD_TAG - 1)
+            );
+            assert_eq!(Float64::from_payload(42).to_integer(), None);
+            assert_eq!(Float64::from_payload(42).to_float(), None);
+        }
+
+        #[test]
+        fn float() {
+            assert_eq!(Float64::from_float(4.2).to_float(), Some(4.2));
+            assert_eq!(Float64::from_float(-4.2).to_float(), Some(-4.2));
+            assert_eq!(Float64::from_float(4.2).to_integer(), None);
+            assert_eq!(Float64::from_float(4.2).to_payload(), None);
+            assert!(Float64::from_float(f64::INFINITY).is_infinite());
+            assert!(Float64::from_float(f64::NEG_INFINITY).is_infinite());
+        }
+
+        #[test]
+        fn nan() {
+            let nan = Float64::from_float(f64::from_bits(box_unsigned(42)));
+
+            assert!(nan.is_nan());
+            assert_eq!(nan.to_payload(), None);
+            assert_eq!(nan.to_integer(), None);
+            assert!(Float64::from_float(-f64::NAN).is_nan());
+        }
+
+        #[test]
+        fn arithmetic() {
+            assert_eq!(
+                Float64::from_integer(2) + Float64::from_integer(3),
+                Float64::from_integer(5)
+            );
+            assert_eq!(
+                Float64::from_integer(2) - Float64::from_float(3.5),
+                Float64::from_float(-1.5)
+            );
+            assert_eq!(
+                Float64::from_float(2.0) * Float64::from_integer(3),
+                Float64::from_integer(6)
+            );
+            assert_eq!(
+                Float64::from_integer(6) / Float64::from_integer(4),
+                Float64::from_float(1.5)
+            );
+            assert_eq!(
+                Float64::from_integer(7) % Float64::from_integer(4),
+                Float64::from_integer(3)
+            );
+            assert_eq!(-Float64::from_integer(42), Float64::from_integer(-42));
+            assert!((Float64::from_payload(1) + Float64::from_integer(1)).is_nan());
+        }
+
+        #[test]
+        fn promote_integer_overflow_to_float() {
+            let maximum = Float64::from_integer(INTEGER_MAXIMUM);
+
+            assert_eq!(
+                (maximum + Float64::from_integer(1)).to_float(),
+                Some((INTEGER_MAXIMUM + 1) as f64)
+            );
+            assert_eq!(
+                (maximum * maximum).to_float(),
+                Some(INTEGER_MAXIMUM as f64 * INTEGER_MAXIMUM as f64)
+            );
+        }
+
+        #[test]
+        fn cmp() {
+            assert_eq!(
+                Float64::from_integer(1).partial_cmp(&Float64::from_integer(2)),
+                Some(Ordering::Less)
+            );
+            assert_eq!(
+                Float64::from_integer(2).partial_cmp(&Float64::from_float(1.5)),
+                Some(Ordering::Greater)
+            );
+            assert_eq!(Float64::from_integer(4), Float64::from_float(4.0));
+            assert_eq!(Float64::from_payload(4), Float64::from_payload(4));
+            assert_ne!(Float64::from_payload(4), Float64::from_integer(4));
+            assert_ne!(Float64::from_float(f64::NAN), Float64::from_float(f64::NAN));
+        }
+
+        #[test]
+        fn format() {
+            assert_eq!(Float64::from_integer(42).to_string(), "42");
+            assert_eq!(Float64::from_float(4.2).to_string(), "4.2");
+            assert_eq!(Float64::from_payload(42).to_string(), "0x2a");
+            assert_eq!(Float64::from_float(f64::NAN).to_string(), "NaN");
+        }
+    }
 }
@@ -0,0 +1,767 @@
+//! NaN boxing with configurable layouts.
+//!
+//! A layout trades integer widths against payload widths and exponent ranges
+//! of floating-point numbers. `Layout<1, 3, 0x300, 0x4ff>` is equivalent to
+//! the one in the [`f62`](crate::f62) module.
+
+use crate::nan_box::{Kind, NanBox};
+use core::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+};
+
+const MANTISSA_WIDTH: u32 = 52;
+const EXPONENT_MASK: u64 = (1 << 11) - 1;
+const EXPONENT_BIAS: u64 = 0x400;
+
+/// A NaN boxing layout.
+///
+/// An integer has `INTEGER_TAG_WIDTH` tag bits and a payload has
+/// `PAYLOAD_TAG_WIDTH` tag bits. A floating-point number keeps its exponent
+/// within `MINIMUM_EXPONENT..=MAXIMUM_EXPONENT` and its highest
+/// `PAYLOAD_TAG_WIDTH - 1` exponent bits are replaced with a tag.
+///
+/// Invalid layouts are rejected at compile time.
+///
+/// ```compile_fail
+/// use nonbox::layout::Layout;
+///
+/// Layout::<1, 3, 0x200, 0x4ff>::box_integer(42);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Layout<
+    const INTEGER_TAG_WIDTH: u32,
+    const PAYLOAD_TAG_WIDTH: u32,
+    const MINIMUM_EXPONENT: u64,
+    const MAXIMUM_EXPONENT: u64,
+>;
+
+impl<
+    const INTEGER_TAG_WIDTH: u32,
+    const PAYLOAD_TAG_WIDTH: u32,
+    const MINIMUM_EXPONENT: u64,
+    const MAXIMUM_EXPONENT: u64,
+> Layout<INTEGER_TAG_WIDTH, PAYLOAD_TAG_WIDTH, MINIMUM_EXPONENT, MAXIMUM_EXPONENT>
+{
+    const ASSERTION: () = {
+        assert!(
+            INTEGER_TAG_WIDTH > 0 && INTEGER_TAG_WIDTH < 64,
+            "integer tag width out of range"
+        );
+        assert!(
+            PAYLOAD_TAG_WIDTH >= 3 && PAYLOAD_TAG_WIDTH <= 11,
+            "payload tag width out of range"
+        );
+        assert!(MINIMUM_EXPONENT <= MAXIMUM_EXPONENT, "empty exponent range");
+        assert!(
+            MINIMUM_EXPONENT >= EXPONENT_BIAS - (1 << (11 - PAYLOAD_TAG_WIDTH)),
+            "minimum exponent out of range"
+        );
+        assert!(
+            MAXIMUM_EXPONENT < EXPONENT_BIAS + (1 << (11 - PAYLOAD_TAG_WIDTH)),
+            "maximum exponent out of range"
+        );
+    };
+
+    const INTEGER_SHIFT: u32 = {
+        let () = Self::ASSERTION;
+        INTEGER_TAG_WIDTH
+    };
+    const PAYLOAD_SHIFT: u32 = {
+        let () = Self::ASSERTION;
+        PAYLOAD_TAG_WIDTH
+    };
+
+    const INTEGER_TAG_MASK: u64 = (1 << Self::INTEGER_SHIFT) - 1;
+    const PAYLOAD_TAG_MASK: u64 = (1 << Self::PAYLOAD_SHIFT) - 1;
+    const FLOAT_TAG: u64 = (1 << (Self::PAYLOAD_SHIFT - 1)) - 1;
+    const EXPONENT_TAIL: u64 = 1 << (Self::PAYLOAD_SHIFT - 2);
+
+    const SPECIAL_TAG: u64 = 1 | 1 << (Self::PAYLOAD_SHIFT - 1);
+    const NAN: u64 = Self::SPECIAL_TAG;
+    const POSITIVE_INFINITY: u64 = (1 << Self::PAYLOAD_SHIFT) | Self::SPECIAL_TAG;
+    const NEGATIVE_INFINITY: u64 = (2 << Self::PAYLOAD_SHIFT) | Self::SPECIAL_TAG;
+
+    /// A bit width of integers.
+    pub const INTEGER_WIDTH: u32 = 64 - Self::INTEGER_SHIFT;
+    /// A bit width of payloads.
+    pub const PAYLOAD_WIDTH: u32 = 64 - Self::PAYLOAD_SHIFT;
+
+    /// Boxes a signed integer.
+    #[inline]
+    pub const fn box_integer(integer: i64) -> u64 {
+        (integer << Self::INTEGER_SHIFT) as _
+    }
+
+    /// Unboxes a signed integer.
+    #[inline]
+    pub const fn unbox_integer(number: u64) -> Option<i64> {
+        if Self::is_integer(number) {
+            Some(Self::unbox_integer_unchecked(number))
+        } else {
+            None
+        }
+    }
+
+    /// Unboxes a signed integer without any type check.
+    #[inline]
+    pub const fn unbox_integer_unchecked(number: u64) -> i64 {
+        number as i64 >> Self::INTEGER_SHIFT
+    }
+
+    /// Returns `true` if a number is an integer.
+    #[inline]
+    pub const fn is_integer(number: u64) -> bool {
+        number & Self::INTEGER_TAG_MASK == 0
+    }
+
+    /// Boxes a payload.
+    #[inline]
+    pub const fn box_payload(payload: u64) -> u64 {
+        (payload << Self::PAYLOAD_SHIFT) | 1
+    }
+
+    /// Unboxes a payload.
+    #[inline]
+    pub const fn unbox_payload(number: u64) -> Option<u64> {
+        if Self::is_payload(number) {
+            Some(Self::unbox_payload_unchecked(number))
+        } else {
+            None
+        }
+    }
+
+    /// Unboxes a payload without any type check.
+    #[inline]
+    pub const fn unbox_payload_unchecked(number: u64) -> u64 {
+        number >> Self::PAYLOAD_SHIFT
+    }
+
+    /// Returns `true` if a number is a payload.
+    #[inline]
+    pub const fn is_payload(number: u64) -> bool {
+        number & Self::PAYLOAD_TAG_MASK == 1
+    }
+
+    /// Boxes a 64-bit floating-point number.
+    #[inline]
+    pub const fn box_float(number: f64) -> u64 {
+        if number == 0.0 {
+            0
+        } else if number.is_nan() {
+            Self::NAN
+        } else if number == f64::INFINITY {
+            Self::POSITIVE_INFINITY
+        } else if number == f64::NEG_INFINITY {
+            Self::NEGATIVE_INFINITY
+        } else {
+            let bits = number.to_bits();
+            let exponent = bits >> MANTISSA_WIDTH & EXPONENT_MASK;
+
+            if exponent < MINIMUM_EXPONENT {
+                0
+            } else if exponent > MAXIMUM_EXPONENT {
+                if number < 0.0 {
+                    Self::NEGATIVE_INFINITY
+                } else {
+                    Self::POSITIVE_INFINITY
+                }
+            } else {
+                bits.rotate_left(Self::PAYLOAD_SHIFT) | Self::FLOAT_TAG
+            }
+        }
+    }
+
+    /// Unboxes a 64-bit floating-point number.
+    #[inline]
+    pub const fn unbox_float(number: u64) -> Option<f64> {
+        if Self::is_float(number) {
+            Some(Self::unbox_float_unchecked(number))
+        } else if Self::is_nan(number) {
+            Some(f64::NAN)
+        } else if number == Self::POSITIVE_INFINITY {
+            Some(f64::INFINITY)
+        } else if number == Self::NEGATIVE_INFINITY {
+            Some(f64::NEG_INFINITY)
+        } else {
+            None
+        }
+    }
+
+    /// Unboxes a 64-bit floating-point number without any type check.
+    #[inline]
+    pub const fn unbox_float_unchecked(number: u64) -> f64 {
+        let exponent_tail = Self::EXPONENT_TAIL - (number >> 63);
+
+        f64::from_bits(
+            (number & !Self::FLOAT_TAG | exponent_tail).rotate_right(Self::PAYLOAD_SHIFT),
+        )
+    }
+
+    /// Returns `true` if a number is a floating-point number.
+    #[inline]
+    pub const fn is_float(number: u64) -> bool {
+        number & Self::FLOAT_TAG == Self::FLOAT_TAG
+    }
+
+    /// Returns `true` if a number is an infinity.
+    #[inline]
+    pub const fn is_infinite(number: u64) -> bool {
+        number == Self::POSITIVE_INFINITY || number == Self::NEGATIVE_INFINITY
+    }
+
+    /// Returns `true` if a number is NaN.
+    #[inline]
+    pub const fn is_nan(number: u64) -> bool {
+        number == Self::NAN
+    }
+}
+
+/// A number boxed in a layout.
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct Value<
+    const INTEGER_TAG_WIDTH: u32,
+    const PAYLOAD_TAG_WIDTH: u32,
+    const MINIMUM_EXPONENT: u64,
+    const MAXIMUM_EXPONENT: u64,
+>(u64);
+
+macro_rules! impl_value {
+    ($(impl $trait:ident { $($body:tt)* })*) => {
+        $(
+            impl<
+                const INTEGER_TAG_WIDTH: u32,
+                const PAYLOAD_TAG_WIDTH: u32,
+                const MINIMUM_EXPONENT: u64,
+                const MAXIMUM_EXPONENT: u64,
+            > $trait
+                for Value<INTEGER_TAG_WIDTH, PAYLOAD_TAG_WIDTH, MINIMUM_EXPONENT, MAXIMUM_EXPONENT>
+            {
+                $($body)*
+            }
+        )*
+    };
+}
+
+impl<
+    const INTEGER_TAG_WIDTH: u32,
+    const PAYLOAD_TAG_WIDTH: u32,
+    const MINIMUM_EXPONENT: u64,
+    const MAXIMUM_EXPONENT: u64,
+> Value<INTEGER_TAG_WIDTH, PAYLOAD_TAG_WIDTH, MINIMUM_EXPONENT, MAXIMUM_EXPONENT>
+{
+    /// Creates a number from its raw representation.
+    #[inline]
+    pub const fn from_bits(number: u64) -> Self {
+        Self(number)
+    }
+
+    /// Returns a raw representation.
+    #[inline]
+    pub const fn to_bits(self) -> u64 {
+        self.0
+    }
+
+    /// Creates a number from a payload.
+    #[inline]
+    pub const fn from_payload(payload: u64) -> Self {
+        Self::from_bits(Layout::<
+            INTEGER_TAG_WIDTH,
+            PAYLOAD_TAG_WIDTH,
+            MINIMUM_EXPONENT,
+            MAXIMUM_EXPONENT,
+        >::box_payload(payload))
+    }
+
+    /// Creates a number from an integer.
+    #[inline]
+    pub const fn from_integer(integer: i64) -> Self {
+        Self::from_bits(Layout::<
+            INTEGER_TAG_WIDTH,
+            PAYLOAD_TAG_WIDTH,
+            MINIMUM_EXPONENT,
+            MAXIMUM_EXPONENT,
+        >::box_integer(integer))
+    }
+
+    /// Creates a number from a 64-bit floating-point number.
+    #[inline]
+    pub const fn from_float(number: f64) -> Self {
+        Self::from_bits(Layout::<
+            INTEGER_TAG_WIDTH,
+            PAYLOAD_TAG_WIDTH,
+            MINIMUM_EXPONENT,
+            MAXIMUM_EXPONENT,
+        >::box_float(number))
+    }
+
+    /// Returns a payload.
+    #[inline]
+    pub const fn to_payload(self) -> Option<u64> {
+        Layout::<INTEGER_TAG_WIDTH, PAYLOAD_TAG_WIDTH, MINIMUM_EXPONENT, MAXIMUM_EXPONENT>::unbox_payload(self.0)
+    }
+
+    /// Returns a payload without any type check.
+    #[inline]
+    pub const fn to_payload_unchecked(self) -> u64 {
+        Layout::<INTEGER_TAG_WIDTH, PAYLOAD_TAG_WIDTH, MINIMUM_EXPONENT, MAXIMUM_EXPONENT>::unbox_payload_unchecked(self.0)
+    }
+
+    /// Returns an integer.
+    #[inline]
+    pub const fn to_integer(self) -> Option<i64> {
+        Layout::<INTEGER_TAG_WIDTH, PAYLOAD_TAG_WIDTH, MINIMUM_EXPONENT, MAXIMUM_EXPONENT>::unbox_integer(self.0)
+    }
+
+    /// Returns an integer without any type check.
+    #[inline]
+    pub const fn to_integer_unchecked(self) -> i64 {
+        Layout::<INTEGER_TAG_WIDTH, PAYLOAD_TAG_WIDTH, MINIMUM_EXPONENT, MAXIMUM_EXPONENT>::unbox_integer_unchecked(self.0)
+    }
+
+    /// Returns a 64-bit floating-point number.
+    #[inline]
+    pub const fn to_float(self) -> Option<f64> {
+        Layout::<INTEGER_TAG_WIDTH, PAYLOAD_TAG_WIDTH, MINIMUM_EXPONENT, MAXIMUM_EXPONENT>::unbox_float(self.0)
+    }
+
+    /// Returns a 64-bit floating-point number without any type check.
+    #[inline]
+    pub const fn to_float_unchecked(self) -> f64 {
+        Layout::<INTEGER_TAG_WIDTH, PAYLOAD_TAG_WIDTH, MINIMUM_EXPONENT, MAXIMUM_EXPONENT>::unbox_float_unchecked(self.0)
+    }
+
+    /// Returns `true` if this number is an infinity.
+    #[inline]
+    pub const fn is_infinite(self) -> bool {
+        Layout::<INTEGER_TAG_WIDTH, PAYLOAD_TAG_WIDTH, MINIMUM_EXPONENT, MAXIMUM_EXPONENT>::is_infinite(self.0)
+    }
+
+    /// Returns `true` if this number is NaN.
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        Layout::<INTEGER_TAG_WIDTH, PAYLOAD_TAG_WIDTH, MINIMUM_EXPONENT, MAXIMUM_EXPONENT>::is_nan(
+            self.0,
+        )
+    }
+
+    const fn is_integer(self) -> bool {
+        Layout::<INTEGER_TAG_WIDTH, PAYLOAD_TAG_WIDTH, MINIMUM_EXPONENT, MAXIMUM_EXPONENT>::is_integer(self.0)
+    }
+
+    const fn is_payload(self) -> bool {
+        Layout::<INTEGER_TAG_WIDTH, PAYLOAD_TAG_WIDTH, MINIMUM_EXPONENT, MAXIMUM_EXPONENT>::is_payload(self.0)
+    }
+
+    #[inline]
+    const fn to_number(self) -> Result<i64, f64> {
+        if let Some(integer) = self.to_integer() {
+            Ok(integer)
+        } else if let Some(float) = self.to_float() {
+            Err(float)
+        } else {
+            Err(f64::NAN)
+        }
+    }
+
+    #[inline]
+    fn operate_float(self, rhs: Self, operate: fn(f64, f64) -> f64) -> Self {
+        Self::from_float(match (self.to_number(), rhs.to_number()) {
+            (Ok(_), Ok(_)) => unreachable!(),
+            (Ok(x), Err(y)) => operate(x as f64, y),
+            (Err(x), Ok(y)) => operate(x, y as f64),
+            (Err(x), Err(y)) => operate(x, y),
+        })
+    }
+
+    /// Calculates the remainder of dividing this number by another number,
+    /// returning `None` when both numbers are integers and the divisor is
+    /// zero.
+    #[inline]
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        let (Some(x), Some(y)) = (self.to_integer(), rhs.to_integer()) else {
+            return Some(self % rhs);
+        };
+
+        Some(Self::from_integer(x.checked_rem(y)?))
+    }
+}
+
+macro_rules! operate {
+    ($lhs:ident, $rhs:ident, $operate:ident, $wrapping_operate:ident) => {{
+        let (Some(x), Some(y)) = ($lhs.to_integer(), $rhs.to_integer()) else {
+            return $lhs.operate_float($rhs, f64::$operate);
+        };
+
+        Self::from_integer(x.$wrapping_operate(y))
+    }};
+}
+
+impl_value! {
+    impl Add {
+        type Output = Self;
+
+        #[inline]
+        fn add(self, rhs: Self) -> Self::Output {
+            operate!(self, rhs, add, wrapping_add)
+        }
+    }
+
+    impl Sub {
+        type Output = Self;
+
+        #[inline]
+        fn sub(self, rhs: Self) -> Self::Output {
+            operate!(self, rhs, sub, wrapping_sub)
+        }
+    }
+
+    impl Mul {
+        type Output = Self;
+
+        #[inline]
+        fn mul(self, rhs: Self) -> Self::Output {
+            operate!(self, rhs, mul, wrapping_mul)
+        }
+    }
+
+    impl Div {
+        type Output = Self;
+
+        #[inline]
+        fn div(self, rhs: Self) -> Self::Output {
+            let (Some(x), Some(y)) = (self.to_integer(), rhs.to_integer()) else {
+                return self.operate_float(rhs, f64::div);
+            };
+
+            if y != 0 && x % y == 0 {
+                Self::from_integer(x / y)
+            } else {
+                Self::from_float(x as f64 / y as f64)
+            }
+        }
+    }
+
+    impl Rem {
+        type Output = Self;
+
+        #[inline]
+        fn rem(self, rhs: Self) -> Self::Output {
+            let (Some(x), Some(y)) = (self.to_integer(), rhs.to_integer()) else {
+                return self.operate_float(rhs, f64::rem);
+            };
+
+            if y == 0 {
+                Self::from_float(f64::NAN)
+            } else {
+                Self::from_integer(x % y)
+            }
+        }
+    }
+
+    impl AddAssign {
+        #[inline]
+        fn add_assign(&mut self, rhs: Self) {
+            *self = *self + rhs;
+        }
+    }
+
+    impl SubAssign {
+        #[inline]
+        fn sub_assign(&mut self, rhs: Self) {
+            *self = *self - rhs;
+        }
+    }
+
+    impl MulAssign {
+        #[inline]
+        fn mul_assign(&mut self, rhs: Self) {
+            *self = *self * rhs;
+        }
+    }
+
+    impl DivAssign {
+        #[inline]
+        fn div_assign(&mut self, rhs: Self) {
+            *self = *self / rhs;
+        }
+    }
+
+    impl Neg {
+        type Output = Self;
+
+        #[inline]
+        fn neg(self) -> Self::Output {
+            match self.to_number() {
+                Ok(x) => Self::from_integer(x.wrapping_neg()),
+                Err(x) => Self::from_float(-x),
+            }
+        }
+    }
+
+    impl Display {
+        #[inline]
+        fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if let Some(integer) = self.to_integer() {
+                write!(formatter, "{integer}")
+            } else if let Some(float) = self.to_float() {
+                write!(formatter, "{float}")
+            } else {
+                write!(formatter, "0x{:x}", self.to_payload_unchecked())
+            }
+        }
+    }
+
+    impl PartialEq {
+        #[inline]
+        fn eq(&self, other: &Self) -> bool {
+            self.partial_cmp(other) == Some(Ordering::Equal)
+        }
+    }
+
+    impl PartialOrd {
+        #[inline]
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            if self.0 == other.0 {
+                return (!self.is_nan()).then_some(Ordering::Equal);
+            }
+
+            match (self.to_number(), other.to_number()) {
+                (Ok(x), Ok(y)) => x.partial_cmp(&y),
+                (Ok(x), Err(y)) => compare_integer_and_float(x, y),
+                (Err(x), Ok(y)) => compare_integer_and_float(y, x).map(Ordering::reverse),
+                (Err(x), Err(y)) => x.partial_cmp(&y),
+            }
+        }
+    }
+
+    impl NanBox {
+        const PAYLOAD_WIDTH: u32 = 64 - PAYLOAD_TAG_WIDTH;
+        const INTEGER_MIN: i64 = i64::MIN >> INTEGER_TAG_WIDTH;
+        const INTEGER_MAX: i64 = i64::MAX >> INTEGER_TAG_WIDTH;
+
+        #[inline]
+        fn from_integer(integer: i64) -> Self {
+            Self::from_integer(integer)
+        }
+
+        #[inline]
+        fn from_float(number: f64) -> Self {
+            Self::from_float(number)
+        }
+
+        #[inline]
+        fn from_payload(payload: u64) -> Self {
+            Self::from_payload(payload)
+        }
+
+        #[inline]
+        fn classify(self) -> Kind {
+            if self.is_integer() {
+                Kind::Integer(self.to_integer_unchecked())
+            } else if self.is_payload() {
+                Kind::Payload(self.to_payload_unchecked())
+            } else {
+                Kind::Float(self.to_float().unwrap_or(f64::NAN))
+            }
+        }
+    }
+}
+
+fn compare_integer_and_float(integer: i64, float: f64) -> Option<Ordering> {
+    let rounded = integer as f64;
+
+    match rounded.partial_cmp(&float)? {
+        Ordering::Equal => integer.partial_cmp(&(rounded as i64)),
+        ordering => Some(ordering),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::f62::{self, Float62};
+
+    type Layout62 = Layout<1, 3, 0x300, 0x4ff>;
+    type Value62 = Value<1, 3, 0x300, 0x4ff>;
+    type WideLayout = Layout<2, 4, 0x380, 0x47f>;
+    type WideValue = Value<2, 4, 0x380, 0x47f>;
+
+    const FLOATS: [f64; 12] = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        4.2,
+        1e-100,
+        1e100,
+        1e300,
+        f64::MIN_POSITIVE,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+
+    #[test]
+    fn match_f62() {
+        for integer in [0, 1, -1, 42, -42, (1 << 62) - 1, -(1 << 62)] {
+            assert_eq!(Layout62::box_integer(integer), f62::box_integer(integer));
+        }
+
+        for payload in [0, 1, 42, (1 << 61) - 1] {
+            assert_eq!(Layout62::box_payload(payload), f62::box_payload(payload));
+        }
+
+        for number in FLOATS {
+            assert_eq!(Layout62::box_float(number), f62::box_float(number));
+            assert_eq!(
+                Layout62::unbox_float(f62::box_float(number)).map(f64::to_bits),
+                f62::unbox_float(f62::box_float(number)).map(f64::to_bits)
+            );
+        }
+    }
+
+    #[test]
+    fn widths() {
+        assert_eq!(Layout62::INTEGER_WIDTH, 63);
+        assert_eq!(Layout62::PAYLOAD_WIDTH, 61);
+        assert_eq!(WideLayout::INTEGER_WIDTH, 62);
+        assert_eq!(WideLayout::PAYLOAD_WIDTH, 60);
+    }
+
+    #[test]
+    fn integer() {
+        for integer in [0, 1, -1, 42, -42, (1 << 61) - 1, -(1 << 61)] {
+            assert!(WideLayout::is_integer(WideLayout::box_integer(integer)));
+            assert_eq!(
+                WideLayout::unbox_integer(WideLayout::box_integer(integer)),
+                Some(integer)
+            );
+        }
+    }
+
+    #[test]
+    fn payload() {
+        for payload in [0, 1, 42, (1 << 60) - 1] {
+            assert!(WideLayout::is_payload(WideLayout::box_payload(payload)));
+            assert!(!WideLayout::is_integer(WideLayout::box_payload(payload)));
+            assert!(!WideLayout::is_float(WideLayout::box_payload(payload)));
+            assert_eq!(
+                WideLayout::unbox_payload(WideLayout::box_payload(payload)),
+                Some(payload)
+            );
+        }
+    }
+
+    #[test]
+    fn float() {
+        let maximum = f64::from_bits(0x47f << MANTISSA_WIDTH);
+        let minimum = f64::from_bits(0x380 << MANTISSA_WIDTH);
+
+        for number in [1.0, -1.0, 4.2, -4.2, maximum, -maximum, minimum, -minimum] {
+            assert!(WideLayout::is_float(WideLayout::box_float(number)));
+            assert_eq!(
+                WideLayout::unbox_float(WideLayout::box_float(number)),
+                Some(number)
+            );
+        }
+
+        assert_eq!(
+            WideLayout::unbox_float(WideLayout::box_float(maximum * 2.0)),
+            Some(f64::INFINITY)
+        );
+        assert_eq!(WideLayout::box_float(minimum / 2.0), 0);
+    }
+
+    #[test]
+    fn special() {
+        for layout in [
+            (
+                WideLayout::box_float(f64::NAN),
+                WideLayout::is_nan as fn(u64) -> bool,
+            ),
+            (
+                WideLayout::box_float(f64::INFINITY),
+                WideLayout::is_infinite,
+            ),
+            (
+                WideLayout::box_float(f64::NEG_INFINITY),
+                WideLayout::is_infinite,
+            ),
+        ] {
+            let (number, check) = layout;
+
+            assert!(check(number));
+            assert!(!WideLayout::is_integer(number));
+            assert!(!WideLayout::is_payload(number));
+            assert!(!WideLayout::is_float(number));
+        }
+    }
+
+    mod value {
+        use super::*;
+        use alloc::string::ToString;
+
+        #[test]
+        fn match_float62() {
+            // Integers wrap around on overflow unlike `Float62`.
+            let values = [0, 1, -1, 42, 1 << 30]
+                .map(|integer| {
+                    (
+                        Value62::from_integer(integer),
+                        Float62::from_integer(integer),
+                    )
+                })
+                .into_iter()
+                .chain(
+                    FLOATS.map(|number| (Value62::from_float(number), Float62::from_float(number))),
+                )
+                .chain([(Value62::from_payload(42), Float62::from_payload(42))]);
+
+            for (x, y) in values.clone() {
+                for (z, w) in values.clone() {
+                    assert_eq!((x + z).to_bits(), (y + w).to_bits());
+                    assert_eq!((x - z).to_bits(), (y - w).to_bits());
+                    assert_eq!((x * z).to_bits(), (y * w).to_bits());
+                    #[cfg(not(feature = "rational"))]
+                    assert_eq!((x / z).to_bits(), (y / w).to_bits());
+                    assert_eq!((x % z).to_bits(), (y % w).to_bits());
+                    assert_eq!(x.partial_cmp(&z), y.partial_cmp(&w));
+                }
+            }
+        }
+
+        #[test]
+        fn arithmetic() {
+            assert_eq!(
+                WideValue::from_integer(2) + WideValue::from_integer(3),
+                WideValue::from_integer(5)
+            );
+            assert_eq!(
+                WideValue::from_integer(7) / WideValue::from_integer(2),
+                WideValue::from_float(3.5)
+            );
+            assert_eq!(
+                WideValue::from_float(1.5) * WideValue::from_integer(2),
+                WideValue::from_integer(3)
+            );
+            assert_eq!(-WideValue::from_integer(42), WideValue::from_integer(-42));
+            assert_eq!(
+                Value62::from_integer((1 << 62) - 1) + Value62::from_integer(1),
+                Value62::from_integer(-(1 << 62))
+            );
+            assert!((WideValue::from_integer(0) / WideValue::from_integer(0)).is_nan());
+            assert!((WideValue::from_float(1e300) * WideValue::from_float(1e300)).is_infinite());
+        }
+
+        #[test]
+        fn format() {
+            assert_eq!(WideValue::from_integer(42).to_string(), "42");
+            assert_eq!(WideValue::from_float(4.2).to_string(), "4.2");
+            assert_eq!(WideValue::from_payload(42).to_string(), "0x2a");
+            assert_eq!(WideValue::from_float(f64::NAN).to_string(), "NaN");
+        }
+    }
+}
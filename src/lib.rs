@@ -1,10 +1,16 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
 
-#[cfg(test)]
+#[cfg(any(test, feature = "alloc"))]
 extern crate alloc;
 #[cfg(test)]
 extern crate std;
 
+pub mod f30;
 pub mod f62;
 pub mod f64;
+pub mod layout;
+pub mod nan_box;
+pub mod offset;
+pub mod punbox;
+pub mod smi;
@@ -0,0 +1,126 @@
+//! A common interface for NaN-boxed numbers.
+
+use core::{
+    fmt::{Debug, Display},
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+/// A decoded NaN-boxed number.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    /// An integer.
+    Integer(i64),
+    /// A floating-point number including infinities and NaN.
+    Float(f64),
+    /// A payload.
+    Payload(u64),
+}
+
+/// A NaN-boxed number.
+pub trait NanBox:
+    Copy
+    + Debug
+    + Default
+    + Display
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    /// A bit width of payloads.
+    const PAYLOAD_WIDTH: u32;
+    /// A minimum integer.
+    const INTEGER_MIN: i64;
+    /// A maximum integer.
+    const INTEGER_MAX: i64;
+
+    /// Creates a number from an integer.
+    fn from_integer(integer: i64) -> Self;
+
+    /// Creates a number from a 64-bit floating-point number.
+    fn from_float(number: f64) -> Self;
+
+    /// Creates a number from a payload.
+    fn from_payload(payload: u64) -> Self;
+
+    /// Decodes a number.
+    fn classify(self) -> Kind;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{f62::Float62, f64::Float64, layout::Value, offset::OffsetBox};
+
+    fn integer<T: NanBox>() {
+        for integer in [0, 1, -1, 42, -42, T::INTEGER_MIN, T::INTEGER_MAX] {
+            assert_eq!(T::from_integer(integer).classify(), Kind::Integer(integer));
+        }
+
+        assert_eq!(T::default().classify(), Kind::Integer(0));
+    }
+
+    fn float<T: NanBox>() {
+        for number in [1.0, -1.0, 4.2, -4.2, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(T::from_float(number).classify(), Kind::Float(number));
+        }
+
+        assert!(matches!(
+            T::from_float(f64::NAN).classify(),
+            Kind::Float(number) if number.is_nan()
+        ));
+    }
+
+    fn payload<T: NanBox>() {
+        for payload in [0, 1, 42, (1 << T::PAYLOAD_WIDTH) - 1] {
+            assert_eq!(T::from_payload(payload).classify(), Kind::Payload(payload));
+        }
+    }
+
+    fn arithmetic<T: NanBox>() {
+        assert_eq!(T::from_integer(2) + T::from_integer(3), T::from_integer(5));
+        assert_eq!(T::from_integer(2) - T::from_float(0.5), T::from_float(1.5));
+        assert_eq!(T::from_float(2.0) * T::from_integer(3), T::from_integer(6));
+        assert_eq!(T::from_integer(3) / T::from_integer(2), T::from_float(1.5));
+        assert_eq!(T::from_integer(3) % T::from_integer(2), T::from_integer(1));
+        assert_eq!(-T::from_integer(42), T::from_integer(-42));
+        assert_eq!(T::default(), T::from_integer(0));
+    }
+
+    macro_rules! test_nan_box {
+        ($name:ident, $type:ty) => {
+            mod $name {
+                use super::*;
+
+                #[test]
+                fn integer() {
+                    super::integer::<$type>();
+                }
+
+                #[test]
+                fn float() {
+                    super::float::<$type>();
+                }
+
+                #[test]
+                fn payload() {
+                    super::payload::<$type>();
+                }
+
+                #[test]
+                fn arithmetic() {
+                    super::arithmetic::<$type>();
+                }
+            }
+        };
+    }
+
+    test_nan_box!(float62, Float62);
+    test_nan_box!(float64, Float64);
+    test_nan_box!(offset_box, OffsetBox);
+    test_nan_box!(layout_value, Value<2, 4, 0x380, 0x47f>);
+}
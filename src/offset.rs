@@ -0,0 +1,568 @@
+//! NaN boxing with offset 64-bit floating-point numbers, 32-bit integers, and
+//! unmodified 48-bit pointers in the style of JavaScriptCore.
+//!
+//! Pointers occupy numbers below 2^49 as they are, floating-point numbers are
+//! offset by 2^49, and integers occupy the highest tag range.
+
+use crate::nan_box::{Kind, NanBox};
+use core::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+};
+
+const FLOAT_OFFSET: u64 = 1 << 49;
+const INTEGER_TAG: u64 = 0xfffe << 48;
+const NAN: u64 = f64::NAN.to_bits() + FLOAT_OFFSET;
+
+/// Boxes a 32-bit signed integer.
+#[inline]
+pub const fn box_integer(integer: i32) -> u64 {
+    INTEGER_TAG | integer as u32 as u64
+}
+
+/// Unboxes a 32-bit signed integer.
+#[inline]
+pub const fn unbox_integer(number: u64) -> Option<i32> {
+    if is_integer(number) {
+        Some(unbox_integer_unchecked(number))
+    } else {
+        None
+    }
+}
+
+/// Unboxes a 32-bit signed integer without any type check.
+#[inline]
+pub const fn unbox_integer_unchecked(number: u64) -> i32 {
+    number as i32
+}
+
+/// Returns `true` if a number is an integer.
+#[inline]
+pub const fn is_integer(number: u64) -> bool {
+    number & INTEGER_TAG == INTEGER_TAG
+}
+
+/// Boxes a 64-bit floating-point number.
+#[inline]
+pub const fn box_float(number: f64) -> u64 {
+    if number.is_nan() {
+        NAN
+    } else {
+        number.to_bits() + FLOAT_OFFSET
+    }
+}
+
+/// Unboxes a 64-bit floating-point number.
+#[inline]
+pub const fn unbox_float(number: u64) -> Option<f64> {
+    if is_float(number) {
+        Some(unbox_float_unchecked(number))
+    } else {
+        None
+    }
+}
+
+/// Unboxes a 64-bit floating-point number without any type check.
+#[inline]
+pub const fn unbox_float_unchecked(number: u64) -> f64 {
+    f64::from_bits(number.wrapping_sub(FLOAT_OFFSET))
+}
+
+/// Returns `true` if a number is a 64-bit floating-point number.
+#[inline]
+pub const fn is_float(number: u64) -> bool {
+    number.wrapping_sub(FLOAT_OFFSET) < INTEGER_TAG - FLOAT_OFFSET
+}
+
+/// Boxes a 48-bit pointer.
+#[inline]
+pub fn box_pointer<T>(pointer: *const T) -> u64 {
+    pointer as _
+}
+
+/// Unboxes a 48-bit pointer.
+#[inline]
+pub const fn unbox_pointer<T>(number: u64) -> Option<*const T> {
+    if is_pointer(number) {
+        Some(unbox_pointer_unchecked(number))
+    } else {
+        None
+    }
+}
+
+/// Unboxes a 48-bit pointer without any type check.
+#[inline]
+pub const fn unbox_pointer_unchecked<T>(number: u64) -> *const T {
+    number as _
+}
+
+/// Returns `true` if a number is a pointer.
+#[inline]
+pub const fn is_pointer(number: u64) -> bool {
+    number < FLOAT_OFFSET
+}
+
+/// Returns `true` if a number is an integer or a floating-point number.
+#[inline]
+pub const fn is_number(number: u64) -> bool {
+    number >= FLOAT_OFFSET
+}
+
+/// A value of a 64-bit floating-point number, a 32-bit integer, or a 48-bit
+/// pointer.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct OffsetBox(u64);
+
+impl OffsetBox {
+    /// Creates a value from its raw representation.
+    #[inline]
+    pub const fn from_bits(number: u64) -> Self {
+        Self(number)
+    }
+
+    /// Returns a raw representation.
+    #[inline]
+    pub const fn to_bits(self) -> u64 {
+        self.0
+    }
+
+    /// Creates a value from a pointer.
+    #[inline]
+    pub fn from_pointer<T>(pointer: *const T) -> Self {
+        Self::from_bits(box_pointer(pointer))
+    }
+
+    /// Creates a value from a 32-bit integer.
+    #[inline]
+    pub const fn from_integer(integer: i32) -> Self {
+        Self::from_bits(box_integer(integer))
+    }
+
+    /// Creates a value from a 64-bit floating-point number.
+    #[inline]
+    pub const fn from_float(number: f64) -> Self {
+        Self::from_bits(box_float(number))
+    }
+
+    /// Returns a pointer.
+    #[inline]
+    pub const fn to_pointer<T>(self) -> Option<*const T> {
+        unbox_pointer(self.0)
+    }
+
+    /// Returns a pointer without any type check.
+    #[inline]
+    pub const fn to_pointer_unchecked<T>(self) -> *const T {
+        unbox_pointer_unchecked(self.0)
+    }
+
+    /// Returns an integer.
+    #[inline]
+    pub const fn to_integer(self) -> Option<i32> {
+        unbox_integer(self.0)
+    }
+
+    /// Returns an integer without any type check.
+    #[inline]
+    pub const fn to_integer_unchecked(self) -> i32 {
+        unbox_integer_unchecked(self.0)
+    }
+
+    /// Returns a 64-bit floating-point number.
+    #[inline]
+    pub const fn to_float(self) -> Option<f64> {
+        unbox_float(self.0)
+    }
+
+    /// Returns a 64-bit floating-point number without any type check.
+    #[inline]
+    pub const fn to_float_unchecked(self) -> f64 {
+        unbox_float_unchecked(self.0)
+    }
+
+    /// Returns `true` if this value is a pointer.
+    #[inline]
+    pub const fn is_pointer(self) -> bool {
+        is_pointer(self.0)
+    }
+
+    /// Returns `true` if this value is an infinity.
+    #[inline]
+    pub const fn is_infinite(self) -> bool {
+        is_float(self.0) && self.to_float_unchecked().is_infinite()
+    }
+
+    /// Returns `true` if this value is NaN.
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        is_float(self.0) && self.to_float_unchecked().is_nan()
+    }
+
+    #[inline]
+    const fn to_number(self) -> Result<i32, f64> {
+        if let Some(integer) = self.to_integer() {
+            Ok(integer)
+        } else if let Some(float) = self.to_float() {
+            Err(float)
+        } else {
+            Err(f64::NAN)
+        }
+    }
+
+    #[inline]
+    const fn to_number_float(self) -> f64 {
+        match self.to_number() {
+            Ok(integer) => integer as f64,
+            Err(float) => float,
+        }
+    }
+}
+
+impl Default for OffsetBox {
+    #[inline]
+    fn default() -> Self {
+        Self::from_integer(0)
+    }
+}
+
+fn operate_float(lhs: OffsetBox, rhs: OffsetBox, operate: fn(f64, f64) -> f64) -> OffsetBox {
+    OffsetBox::from_float(operate(lhs.to_number_float(), rhs.to_number_float()))
+}
+
+macro_rules! operate {
+    ($lhs:ident, $rhs:ident, $operate:ident, $checked_operate:ident) => {{
+        let (Some(x), Some(y)) = ($lhs.to_integer(), $rhs.to_integer()) else {
+            return operate_float($lhs, $rhs, f64::$operate);
+        };
+
+        x.$checked_operate(y).map_or_else(
+            || operate_float($lhs, $rhs, f64::$operate),
+            Self::from_integer,
+        )
+    }};
+}
+
+impl Add for OffsetBox {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        operate!(self, rhs, add, checked_add)
+    }
+}
+
+impl Sub for OffsetBox {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        operate!(self, rhs, sub, checked_sub)
+    }
+}
+
+impl Mul for OffsetBox {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        operate!(self, rhs, mul, checked_mul)
+    }
+}
+
+impl Div for OffsetBox {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        let (Some(x), Some(y)) = (self.to_integer(), rhs.to_integer()) else {
+            return operate_float(self, rhs, f64::div);
+        };
+
+        match x.checked_rem(y) {
+            Some(0) => Self::from_integer(x / y),
+            _ => Self::from_float(x as f64 / y as f64),
+        }
+    }
+}
+
+impl Rem for OffsetBox {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, rhs: Self) -> Self::Output {
+        let (Some(x), Some(y)) = (self.to_integer(), rhs.to_integer()) else {
+            return operate_float(self, rhs, f64::rem);
+        };
+
+        if y == 0 {
+            Self::from_float(f64::NAN)
+        } else {
+            Self::from_integer(x.wrapping_rem(y))
+        }
+    }
+}
+
+impl AddAssign for OffsetBox {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for OffsetBox {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for OffsetBox {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for OffsetBox {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Neg for OffsetBox {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        match self.to_number() {
+            Ok(x) => x
+                .checked_neg()
+                .map_or_else(|| Self::from_float(-(x as f64)), Self::from_integer),
+            Err(x) => Self::from_float(-x),
+        }
+    }
+}
+
+impl Display for OffsetBox {
+    #[inline]
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(integer) = self.to_integer() {
+            write!(formatter, "{integer}")
+        } else if let Some(float) = self.to_float() {
+            write!(formatter, "{float}")
+        } else {
+            write!(formatter, "{:p}", self.to_pointer_unchecked::<u8>())
+        }
+    }
+}
+
+impl PartialEq for OffsetBox {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for OffsetBox {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.0 == other.0 {
+            return (!self.is_nan()).then_some(Ordering::Equal);
+        }
+
+        match (self.to_number(), other.to_number()) {
+            (Ok(x), Ok(y)) => x.partial_cmp(&y),
+            _ => self.to_number_float().partial_cmp(&other.to_number_float()),
+        }
+    }
+}
+
+impl NanBox for OffsetBox {
+    const PAYLOAD_WIDTH: u32 = 49;
+    const INTEGER_MIN: i64 = i32::MIN as _;
+    const INTEGER_MAX: i64 = i32::MAX as _;
+
+    #[inline]
+    fn from_integer(integer: i64) -> Self {
+        i32::try_from(integer).map_or_else(|_| Self::from_float(integer as f64), Self::from_integer)
+    }
+
+    #[inline]
+    fn from_float(number: f64) -> Self {
+        Self::from_float(number)
+    }
+
+    #[inline]
+    fn from_payload(payload: u64) -> Self {
+        Self::from_bits(payload & (FLOAT_OFFSET - 1))
+    }
+
+    #[inline]
+    fn classify(self) -> Kind {
+        if is_integer(self.0) {
+            Kind::Integer(self.to_integer_unchecked() as _)
+        } else if is_pointer(self.0) {
+            Kind::Payload(self.0)
+        } else {
+            Kind::Float(self.to_float_unchecked())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{boxed::Box, string::ToString};
+
+    #[test]
+    fn integer() {
+        for integer in [0, 1, -1, 42, -42, i32::MAX, i32::MIN] {
+            assert!(is_integer(box_integer(integer)));
+            assert!(is_number(box_integer(integer)));
+            assert!(!is_float(box_integer(integer)));
+            assert!(!is_pointer(box_integer(integer)));
+            assert_eq!(unbox_integer(box_integer(integer)), Some(integer));
+        }
+    }
+
+    #[test]
+    fn float() {
+        for number in [
+            0.0,
+            -0.0,
+            1.0,
+            -1.0,
+            4.2,
+            f64::MAX,
+            f64::MIN,
+            f64::MIN_POSITIVE,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ] {
+            assert!(is_float(box_float(number)));
+            assert!(is_number(box_float(number)));
+            assert!(!is_integer(box_float(number)));
+            assert!(!is_pointer(box_float(number)));
+            assert_eq!(
+                unbox_float(box_float(number)).map(f64::to_bits),
+                Some(number.to_bits())
+            );
+        }
+    }
+
+    #[test]
+    fn nan() {
+        for number in [f64::NAN, -f64::NAN, f64::from_bits(u64::MAX)] {
+            assert_eq!(box_float(number), NAN);
+            assert!(is_float(box_float(number)));
+            assert!(unbox_float(box_float(number)).unwrap().is_nan());
+        }
+    }
+
+    #[test]
+    fn pointer() {
+        let value = Box::new(42);
+        let pointer = &*value as *const i32;
+
+        assert_eq!(box_pointer(pointer), pointer as u64);
+        assert!(is_pointer(box_pointer(pointer)));
+        assert!(!is_number(box_pointer(pointer)));
+        assert_eq!(unbox_pointer(box_pointer(pointer)), Some(pointer));
+        assert_eq!(unbox_pointer::<i32>(box_integer(42)), None);
+        assert_eq!(unbox_pointer::<i32>(box_float(4.2)), None);
+        assert!(is_pointer(box_pointer(core::ptr::null::<u8>())));
+    }
+
+    mod offset_box {
+        use super::*;
+
+        #[test]
+        fn default() {
+            assert_eq!(OffsetBox::default(), OffsetBox::from_integer(0));
+            assert_eq!(OffsetBox::default(), OffsetBox::from_float(0.0));
+        }
+
+        #[test]
+        fn pointer() {
+            let value = Box::new(42);
+            let pointer = &*value as *const i32;
+            let boxed = OffsetBox::from_pointer(pointer);
+
+            assert!(boxed.is_pointer());
+            assert_eq!(boxed.to_pointer(), Some(pointer));
+            assert_eq!(unsafe { *boxed.to_pointer_unchecked::<i32>() }, 42);
+            assert_eq!(boxed.to_integer(), None);
+            assert_eq!(boxed.to_float(), None);
+        }
+
+        #[test]
+        fn arithmetic() {
+            assert_eq!(
+                OffsetBox::from_integer(2) + OffsetBox::from_integer(3),
+                OffsetBox::from_integer(5)
+            );
+            assert_eq!(
+                OffsetBox::from_integer(2) - OffsetBox::from_float(0.5),
+                OffsetBox::from_float(1.5)
+            );
+            assert_eq!(
+                OffsetBox::from_integer(7) / OffsetBox::from_integer(2),
+                OffsetBox::from_float(3.5)
+            );
+            assert_eq!(
+                OffsetBox::from_integer(7) % OffsetBox::from_integer(2),
+                OffsetBox::from_integer(1)
+            );
+            assert!(
+                (OffsetBox::from_pointer(core::ptr::null::<u8>()) + OffsetBox::from_integer(1))
+                    .is_nan()
+            );
+        }
+
+        #[test]
+        fn promote_integer_overflow_to_float() {
+            assert_eq!(
+                (OffsetBox::from_integer(i32::MAX) + OffsetBox::from_integer(1)).to_float(),
+                Some(i32::MAX as f64 + 1.0)
+            );
+            assert_eq!(
+                (OffsetBox::from_integer(i32::MIN) / OffsetBox::from_integer(-1)).to_float(),
+                Some(-(i32::MIN as f64))
+            );
+            assert_eq!(
+                (-OffsetBox::from_integer(i32::MIN)).to_float(),
+                Some(-(i32::MIN as f64))
+            );
+            assert_eq!(
+                OffsetBox::from_integer(i32::MIN) % OffsetBox::from_integer(-1),
+                OffsetBox::from_integer(0)
+            );
+        }
+
+        #[test]
+        fn cmp() {
+            assert_eq!(
+                OffsetBox::from_integer(1).partial_cmp(&OffsetBox::from_float(1.5)),
+                Some(Ordering::Less)
+            );
+            assert_eq!(OffsetBox::from_integer(4), OffsetBox::from_float(4.0));
+            assert_ne!(
+                OffsetBox::from_float(f64::NAN),
+                OffsetBox::from_float(f64::NAN)
+            );
+        }
+
+        #[test]
+        fn format() {
+            assert_eq!(OffsetBox::from_integer(42).to_string(), "42");
+            assert_eq!(OffsetBox::from_float(4.2).to_string(), "4.2");
+            assert_eq!(
+                OffsetBox::from_pointer(0x2a as *const u8).to_string(),
+                "0x2a"
+            );
+        }
+    }
+}
@@ -0,0 +1,515 @@
+//! NaN boxing with 17-bit tags and 47-bit payloads in the style of
+//! SpiderMonkey's punboxing.
+//!
+//! Any number below the smallest tag is a 64-bit floating-point number as it
+//! is. Tags for pointers are the largest so that a pointer check is a single
+//! comparison.
+
+const TAG_SHIFT: u32 = 47;
+const PAYLOAD_MASK: u64 = (1 << TAG_SHIFT) - 1;
+const MAXIMUM_FLOAT_TAG: u64 = 0x1fff0;
+
+const MINIMUM_TAGGED: u64 = Tag::Integer.to_shifted();
+const MINIMUM_POINTER: u64 = Tag::String.to_shifted();
+
+/// A tag of a boxed value.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(u8)]
+pub enum Tag {
+    /// A 32-bit signed integer.
+    Integer = 1,
+    /// A boolean.
+    Boolean,
+    /// An undefined value.
+    Undefined,
+    /// A null value.
+    Null,
+    /// A pointer to a string.
+    String,
+    /// A pointer to a symbol.
+    Symbol,
+    /// A pointer to any other object.
+    Pointer,
+}
+
+impl Tag {
+    const fn from_raw(tag: u64) -> Option<Self> {
+        Some(match tag {
+            1 => Self::Integer,
+            2 => Self::Boolean,
+            3 => Self::Undefined,
+            4 => Self::Null,
+            5 => Self::String,
+            6 => Self::Symbol,
+            7 => Self::Pointer,
+            _ => return None,
+        })
+    }
+
+    const fn to_shifted(self) -> u64 {
+        (MAXIMUM_FLOAT_TAG | self as u64) << TAG_SHIFT
+    }
+
+    /// Returns `true` if a tag is for pointers.
+    #[inline]
+    pub const fn is_pointer(self) -> bool {
+        self.to_shifted() >= MINIMUM_POINTER
+    }
+}
+
+/// Boxes a 47-bit payload with a tag.
+#[inline]
+pub const fn box_payload(tag: Tag, payload: u64) -> u64 {
+    tag.to_shifted() | payload & PAYLOAD_MASK
+}
+
+/// Unboxes a 47-bit payload with a tag.
+#[inline]
+pub const fn unbox_payload(number: u64, tag: Tag) -> Option<u64> {
+    if has_tag(number, tag) {
+        Some(unbox_payload_unchecked(number))
+    } else {
+        None
+    }
+}
+
+/// Unboxes a 47-bit payload without any type check.
+#[inline]
+pub const fn unbox_payload_unchecked(number: u64) -> u64 {
+    number & PAYLOAD_MASK
+}
+
+/// Returns a tag of a number.
+#[inline]
+pub const fn unbox_tag(number: u64) -> Option<Tag> {
+    if is_float(number) {
+        None
+    } else {
+        Tag::from_raw((number >> TAG_SHIFT) & !MAXIMUM_FLOAT_TAG)
+    }
+}
+
+/// Returns `true` if a number has a tag.
+#[inline]
+pub const fn has_tag(number: u64, tag: Tag) -> bool {
+    number >> TAG_SHIFT == tag.to_shifted() >> TAG_SHIFT
+}
+
+/// Boxes a 32-bit signed integer.
+#[inline]
+pub const fn box_integer(integer: i32) -> u64 {
+    box_payload(Tag::Integer, integer as u32 as _)
+}
+
+/// Unboxes a 32-bit signed integer.
+#[inline]
+pub const fn unbox_integer(number: u64) -> Option<i32> {
+    if is_integer(number) {
+        Some(unbox_integer_unchecked(number))
+    } else {
+        None
+    }
+}
+
+/// Unboxes a 32-bit signed integer without any type check.
+#[inline]
+pub const fn unbox_integer_unchecked(number: u64) -> i32 {
+    number as i32
+}
+
+/// Returns `true` if a number is an integer.
+#[inline]
+pub const fn is_integer(number: u64) -> bool {
+    has_tag(number, Tag::Integer)
+}
+
+/// Boxes a boolean.
+#[inline]
+pub const fn box_boolean(boolean: bool) -> u64 {
+    box_payload(Tag::Boolean, boolean as _)
+}
+
+/// Unboxes a boolean.
+#[inline]
+pub const fn unbox_boolean(number: u64) -> Option<bool> {
+    if is_boolean(number) {
+        Some(unbox_boolean_unchecked(number))
+    } else {
+        None
+    }
+}
+
+/// Unboxes a boolean without any type check.
+#[inline]
+pub const fn unbox_boolean_unchecked(number: u64) -> bool {
+    number & 1 == 1
+}
+
+/// Returns `true` if a number is a boolean.
+#[inline]
+pub const fn is_boolean(number: u64) -> bool {
+    has_tag(number, Tag::Boolean)
+}
+
+/// Boxes a 47-bit pointer to a string.
+#[inline]
+pub fn box_string<T>(pointer: *const T) -> u64 {
+    box_payload(Tag::String, pointer as _)
+}
+
+/// Unboxes a 47-bit pointer to a string.
+#[inline]
+pub const fn unbox_string<T>(number: u64) -> Option<*const T> {
+    if is_string(number) {
+        Some(unbox_pointer_unchecked(number))
+    } else {
+        None
+    }
+}
+
+/// Returns `true` if a number is a pointer to a string.
+#[inline]
+pub const fn is_string(number: u64) -> bool {
+    has_tag(number, Tag::String)
+}
+
+/// Boxes a 47-bit pointer with a tag.
+///
+/// The tag must be for pointers.
+#[inline]
+pub fn box_pointer<T>(tag: Tag, pointer: *const T) -> u64 {
+    debug_assert!(tag.is_pointer());
+
+    box_payload(tag, pointer as _)
+}
+
+/// Unboxes a 47-bit pointer of any tag.
+#[inline]
+pub const fn unbox_pointer<T>(number: u64) -> Option<*const T> {
+    if is_pointer(number) {
+        Some(unbox_pointer_unchecked(number))
+    } else {
+        None
+    }
+}
+
+/// Unboxes a 47-bit pointer without any type check.
+#[inline]
+pub const fn unbox_pointer_unchecked<T>(number: u64) -> *const T {
+    unbox_payload_unchecked(number) as _
+}
+
+/// Returns `true` if a number is a pointer of any tag.
+#[inline]
+pub const fn is_pointer(number: u64) -> bool {
+    number >= MINIMUM_POINTER
+}
+
+/// Boxes a 64-bit floating-point number.
+#[inline]
+pub const fn box_float(number: f64) -> u64 {
+    if number.is_nan() {
+        f64::NAN.to_bits()
+    } else {
+        number.to_bits()
+    }
+}
+
+/// Unboxes a 64-bit floating-point number.
+#[inline]
+pub const fn unbox_float(number: u64) -> Option<f64> {
+    if is_float(number) {
+        Some(unbox_float_unchecked(number))
+    } else {
+        None
+    }
+}
+
+/// Unboxes a 64-bit floating-point number without any type check.
+#[inline]
+pub const fn unbox_float_unchecked(number: u64) -> f64 {
+    f64::from_bits(number)
+}
+
+/// Returns `true` if a number is a 64-bit floating-point number.
+#[inline]
+pub const fn is_float(number: u64) -> bool {
+    number < MINIMUM_TAGGED
+}
+
+/// A 64-bit floating-point number or a tagged 47-bit payload.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(transparent)]
+pub struct Punbox(u64);
+
+impl Punbox {
+    /// An undefined value.
+    pub const UNDEFINED: Self = Self::from_payload(Tag::Undefined, 0);
+    /// A null value.
+    pub const NULL: Self = Self::from_payload(Tag::Null, 0);
+
+    /// Creates a value from its raw representation.
+    #[inline]
+    pub const fn from_bits(number: u64) -> Self {
+        Self(number)
+    }
+
+    /// Returns a raw representation.
+    #[inline]
+    pub const fn to_bits(self) -> u64 {
+        self.0
+    }
+
+    /// Creates a value from a payload with a tag.
+    #[inline]
+    pub const fn from_payload(tag: Tag, payload: u64) -> Self {
+        Self::from_bits(box_payload(tag, payload))
+    }
+
+    /// Creates a value from an integer.
+    #[inline]
+    pub const fn from_integer(integer: i32) -> Self {
+        Self::from_bits(box_integer(integer))
+    }
+
+    /// Creates a value from a boolean.
+    #[inline]
+    pub const fn from_boolean(boolean: bool) -> Self {
+        Self::from_bits(box_boolean(boolean))
+    }
+
+    /// Creates a value from a pointer with a tag.
+    #[inline]
+    pub fn from_pointer<T>(tag: Tag, pointer: *const T) -> Self {
+        Self::from_bits(box_pointer(tag, pointer))
+    }
+
+    /// Creates a value from a 64-bit floating-point number.
+    #[inline]
+    pub const fn from_float(number: f64) -> Self {
+        Self::from_bits(box_float(number))
+    }
+
+    /// Returns a tag.
+    #[inline]
+    pub const fn to_tag(self) -> Option<Tag> {
+        unbox_tag(self.0)
+    }
+
+    /// Returns a payload with a tag.
+    #[inline]
+    pub const fn to_payload(self, tag: Tag) -> Option<u64> {
+        unbox_payload(self.0, tag)
+    }
+
+    /// Returns a payload without any type check.
+    #[inline]
+    pub const fn to_payload_unchecked(self) -> u64 {
+        unbox_payload_unchecked(self.0)
+    }
+
+    /// Returns an integer.
+    #[inline]
+    pub const fn to_integer(self) -> Option<i32> {
+        unbox_integer(self.0)
+    }
+
+    /// Returns an integer without any type check.
+    #[inline]
+    pub const fn to_integer_unchecked(self) -> i32 {
+        unbox_integer_unchecked(self.0)
+    }
+
+    /// Returns a boolean.
+    #[inline]
+    pub const fn to_boolean(self) -> Option<bool> {
+        unbox_boolean(self.0)
+    }
+
+    /// Returns a pointer of any tag.
+    #[inline]
+    pub const fn to_pointer<T>(self) -> Option<*const T> {
+        unbox_pointer(self.0)
+    }
+
+    /// Returns a pointer without any type check.
+    #[inline]
+    pub const fn to_pointer_unchecked<T>(self) -> *const T {
+        unbox_pointer_unchecked(self.0)
+    }
+
+    /// Returns a 64-bit floating-point number.
+    #[inline]
+    pub const fn to_float(self) -> Option<f64> {
+        unbox_float(self.0)
+    }
+
+    /// Returns a 64-bit floating-point number without any type check.
+    #[inline]
+    pub const fn to_float_unchecked(self) -> f64 {
+        unbox_float_unchecked(self.0)
+    }
+
+    /// Returns `true` if this value is a pointer of any tag.
+    #[inline]
+    pub const fn is_pointer(self) -> bool {
+        is_pointer(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+
+    const TAGS: [Tag; 7] = [
+        Tag::Integer,
+        Tag::Boolean,
+        Tag::Undefined,
+        Tag::Null,
+        Tag::String,
+        Tag::Symbol,
+        Tag::Pointer,
+    ];
+
+    #[test]
+    fn check_tags() {
+        assert!(f64::from_bits(MINIMUM_TAGGED).is_nan());
+        assert!(is_float(f64::NAN.to_bits()));
+        assert!(is_float((-f64::NAN).to_bits()));
+        assert!(is_float(f64::NEG_INFINITY.to_bits()));
+        assert!(!is_float(MINIMUM_TAGGED));
+    }
+
+    #[test]
+    fn tag() {
+        for tag in TAGS {
+            assert_eq!(unbox_tag(box_payload(tag, 0)), Some(tag));
+            assert_eq!(unbox_tag(box_payload(tag, PAYLOAD_MASK)), Some(tag));
+            assert!(!is_float(box_payload(tag, 0)));
+        }
+
+        assert_eq!(unbox_tag(box_float(4.2)), None);
+        assert_eq!(unbox_tag(u64::MAX), None);
+    }
+
+    #[test]
+    fn pointer_tags() {
+        for tag in TAGS {
+            assert_eq!(
+                is_pointer(box_payload(tag, 0)),
+                matches!(tag, Tag::String | Tag::Symbol | Tag::Pointer)
+            );
+            assert_eq!(is_pointer(box_payload(tag, 0)), tag.is_pointer());
+        }
+    }
+
+    #[test]
+    fn payload() {
+        assert_eq!(
+            unbox_payload(box_payload(Tag::Null, 42), Tag::Null),
+            Some(42)
+        );
+        assert_eq!(
+            unbox_payload(box_payload(Tag::Null, 42), Tag::Undefined),
+            None
+        );
+        assert_eq!(
+            unbox_payload(box_payload(Tag::Symbol, PAYLOAD_MASK), Tag::Symbol),
+            Some(PAYLOAD_MASK)
+        );
+        assert_eq!(
+            unbox_payload(box_payload(Tag::Symbol, PAYLOAD_MASK + 1), Tag::Symbol),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn integer() {
+        for integer in [0, 1, -1, 42, -42, i32::MAX, i32::MIN] {
+            assert!(is_integer(box_integer(integer)));
+            assert!(!is_float(box_integer(integer)));
+            assert!(!is_pointer(box_integer(integer)));
+            assert_eq!(unbox_integer(box_integer(integer)), Some(integer));
+        }
+
+        assert_eq!(unbox_integer(box_float(42.0)), None);
+        assert_eq!(unbox_integer(box_boolean(true)), None);
+    }
+
+    #[test]
+    fn boolean() {
+        assert_eq!(unbox_boolean(box_boolean(false)), Some(false));
+        assert_eq!(unbox_boolean(box_boolean(true)), Some(true));
+        assert_eq!(unbox_boolean(box_integer(1)), None);
+    }
+
+    #[test]
+    fn string() {
+        let string = Box::new("foo");
+        let pointer = &*string as *const &str;
+
+        assert!(is_string(box_string(pointer)));
+        assert!(is_pointer(box_string(pointer)));
+        assert_eq!(unbox_string(box_string(pointer)), Some(pointer));
+        assert_eq!(unbox_pointer(box_string(pointer)), Some(pointer));
+        assert_eq!(unbox_string::<u8>(box_pointer(Tag::Pointer, pointer)), None);
+    }
+
+    #[test]
+    fn float() {
+        for number in [
+            0.0,
+            -0.0,
+            4.2,
+            -4.2,
+            f64::MAX,
+            f64::MIN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ] {
+            assert!(is_float(box_float(number)));
+            assert_eq!(
+                unbox_float(box_float(number)).map(f64::to_bits),
+                Some(number.to_bits())
+            );
+        }
+
+        assert!(
+            unbox_float(box_float(f64::from_bits(u64::MAX)))
+                .unwrap()
+                .is_nan()
+        );
+    }
+
+    mod punbox {
+        use super::*;
+
+        #[test]
+        fn constants() {
+            assert_eq!(Punbox::UNDEFINED.to_tag(), Some(Tag::Undefined));
+            assert_eq!(Punbox::NULL.to_tag(), Some(Tag::Null));
+            assert_ne!(Punbox::UNDEFINED, Punbox::NULL);
+        }
+
+        #[test]
+        fn convert() {
+            let value = Box::new(42);
+            let pointer = &*value as *const i32;
+
+            assert_eq!(Punbox::from_integer(42).to_integer(), Some(42));
+            assert_eq!(Punbox::from_boolean(true).to_boolean(), Some(true));
+            assert_eq!(Punbox::from_float(4.2).to_float(), Some(4.2));
+            assert_eq!(
+                Punbox::from_pointer(Tag::Pointer, pointer).to_pointer(),
+                Some(pointer)
+            );
+            assert_eq!(
+                Punbox::from_payload(Tag::Symbol, 42).to_payload(Tag::Symbol),
+                Some(42)
+            );
+            assert_eq!(Punbox::from_integer(42).to_float(), None);
+            assert_eq!(Punbox::from_float(42.0).to_integer(), None);
+        }
+    }
+}
@@ -0,0 +1,315 @@
+//! Pointer tagging for 63-bit integers and aligned pointers without
+//! floating-point numbers in the style of V8's small integers.
+//!
+//! Integers are boxed in the same way as the [`f62`](crate::f62) module.
+
+pub use crate::f62::{box_integer, is_integer, unbox_integer, unbox_integer_unchecked};
+
+/// Boxes a pointer aligned to at least 2 bytes.
+#[inline]
+pub fn box_pointer<T>(pointer: *const T) -> u64 {
+    pointer as u64 | 1
+}
+
+/// Unboxes a pointer.
+#[inline]
+pub const fn unbox_pointer<T>(number: u64) -> Option<*const T> {
+    if is_pointer(number) {
+        Some(unbox_pointer_unchecked(number))
+    } else {
+        None
+    }
+}
+
+/// Unboxes a pointer without any type check.
+#[inline]
+pub const fn unbox_pointer_unchecked<T>(number: u64) -> *const T {
+    (number & !1) as _
+}
+
+/// Returns `true` if a number is a pointer.
+#[inline]
+pub const fn is_pointer(number: u64) -> bool {
+    number & 1 == 1
+}
+
+/// A tagged 63-bit integer or pointer.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(transparent)]
+pub struct Tagged(u64);
+
+impl Tagged {
+    /// Creates a tagged value from its raw representation.
+    #[inline]
+    pub const fn from_bits(number: u64) -> Self {
+        Self(number)
+    }
+
+    /// Returns a raw representation.
+    #[inline]
+    pub const fn to_bits(self) -> u64 {
+        self.0
+    }
+
+    /// Creates a tagged value from a pointer aligned to at least 2 bytes.
+    #[inline]
+    pub fn from_pointer<T>(pointer: *const T) -> Self {
+        Self::from_bits(box_pointer(pointer))
+    }
+
+    /// Creates a tagged value from an integer.
+    #[inline]
+    pub const fn from_integer(integer: i64) -> Self {
+        Self::from_bits(box_integer(integer))
+    }
+
+    /// Returns a pointer.
+    #[inline]
+    pub const fn to_pointer<T>(self) -> Option<*const T> {
+        unbox_pointer(self.0)
+    }
+
+    /// Returns a pointer without any type check.
+    #[inline]
+    pub const fn to_pointer_unchecked<T>(self) -> *const T {
+        unbox_pointer_unchecked(self.0)
+    }
+
+    /// Returns an integer.
+    #[inline]
+    pub const fn to_integer(self) -> Option<i64> {
+        unbox_integer(self.0)
+    }
+
+    /// Returns an integer without any type check.
+    #[inline]
+    pub const fn to_integer_unchecked(self) -> i64 {
+        unbox_integer_unchecked(self.0)
+    }
+
+    /// Returns `true` if this value is an integer.
+    #[inline]
+    pub const fn is_integer(self) -> bool {
+        is_integer(self.0)
+    }
+
+    /// Returns `true` if this value is a pointer.
+    #[inline]
+    pub const fn is_pointer(self) -> bool {
+        is_pointer(self.0)
+    }
+
+    #[inline]
+    const fn to_integers(self, rhs: Self) -> Option<(i64, i64)> {
+        if is_integer(self.0 | rhs.0) {
+            Some((self.0 as _, rhs.0 as _))
+        } else {
+            None
+        }
+    }
+
+    /// Adds two integers, returning `None` on overflow or for pointers.
+    #[inline]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        let Some((x, y)) = self.to_integers(rhs) else {
+            return None;
+        };
+        let Some(z) = x.checked_add(y) else {
+            return None;
+        };
+
+        Some(Self(z as _))
+    }
+
+    /// Subtracts two integers, returning `None` on overflow or for pointers.
+    #[inline]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let Some((x, y)) = self.to_integers(rhs) else {
+            return None;
+        };
+        let Some(z) = x.checked_sub(y) else {
+            return None;
+        };
+
+        Some(Self(z as _))
+    }
+
+    /// Multiplies two integers, returning `None` on overflow or for pointers.
+    #[inline]
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let Some((x, y)) = self.to_integers(rhs) else {
+            return None;
+        };
+        let Some(z) = x.checked_mul(y >> 1) else {
+            return None;
+        };
+
+        Some(Self(z as _))
+    }
+
+    /// Divides two integers, returning `None` on overflow, on division by
+    /// zero, or for pointers.
+    #[inline]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        let Some((x, y)) = self.to_integers(rhs) else {
+            return None;
+        };
+        let Some(z) = x.checked_div(y) else {
+            return None;
+        };
+        let Some(z) = z.checked_mul(2) else {
+            return None;
+        };
+
+        Some(Self(z as _))
+    }
+
+    /// Calculates a remainder of two integers, returning `None` on division
+    /// by zero or for pointers.
+    #[inline]
+    pub const fn checked_rem(self, rhs: Self) -> Option<Self> {
+        let Some((x, y)) = self.to_integers(rhs) else {
+            return None;
+        };
+
+        if y == 0 {
+            None
+        } else {
+            Some(Self(x.wrapping_rem(y) as _))
+        }
+    }
+
+    /// Negates an integer, returning `None` on overflow or for a pointer.
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Self> {
+        let Some((x, _)) = self.to_integers(self) else {
+            return None;
+        };
+        let Some(z) = x.checked_neg() else {
+            return None;
+        };
+
+        Some(Self(z as _))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+
+    const INTEGER_LIMIT: i64 = 1 << 62;
+
+    #[test]
+    fn integer() {
+        for integer in [0, 1, -1, 42, -42, INTEGER_LIMIT - 1, -INTEGER_LIMIT] {
+            assert!(is_integer(box_integer(integer)));
+            assert!(!is_pointer(box_integer(integer)));
+            assert_eq!(unbox_integer(box_integer(integer)), Some(integer));
+        }
+    }
+
+    #[test]
+    fn pointer() {
+        let value = Box::new(42u16);
+        let pointer = &*value as *const u16;
+
+        assert!(is_pointer(box_pointer(pointer)));
+        assert!(!is_integer(box_pointer(pointer)));
+        assert_eq!(unbox_pointer(box_pointer(pointer)), Some(pointer));
+        assert_eq!(unbox_pointer::<u16>(box_integer(42)), None);
+        assert_eq!(unbox_integer(box_pointer(pointer)), None);
+    }
+
+    mod tagged {
+        use super::*;
+
+        #[test]
+        fn default() {
+            assert_eq!(Tagged::default(), Tagged::from_integer(0));
+        }
+
+        #[test]
+        fn pointer() {
+            let value = Box::new(42u16);
+            let pointer = &*value as *const u16;
+            let tagged = Tagged::from_pointer(pointer);
+
+            assert!(tagged.is_pointer());
+            assert!(!tagged.is_integer());
+            assert_eq!(tagged.to_pointer(), Some(pointer));
+            assert_eq!(unsafe { *tagged.to_pointer_unchecked::<u16>() }, 42);
+            assert_eq!(tagged.to_integer(), None);
+        }
+
+        #[test]
+        fn arithmetic() {
+            let x = Tagged::from_integer(7);
+            let y = Tagged::from_integer(-2);
+
+            assert_eq!(x.checked_add(y), Some(Tagged::from_integer(5)));
+            assert_eq!(x.checked_sub(y), Some(Tagged::from_integer(9)));
+            assert_eq!(x.checked_mul(y), Some(Tagged::from_integer(-14)));
+            assert_eq!(x.checked_div(y), Some(Tagged::from_integer(-3)));
+            assert_eq!(x.checked_rem(y), Some(Tagged::from_integer(1)));
+            assert_eq!(x.checked_neg(), Some(Tagged::from_integer(-7)));
+        }
+
+        #[test]
+        fn arithmetic_matches_reference() {
+            let values = [
+                0,
+                1,
+                -1,
+                42,
+                -42,
+                1 << 31,
+                -(1 << 31),
+                INTEGER_LIMIT - 1,
+                -INTEGER_LIMIT,
+            ];
+            let check = |z: Option<i64>| {
+                z.filter(|z| (-INTEGER_LIMIT..INTEGER_LIMIT).contains(z))
+                    .map(Tagged::from_integer)
+            };
+
+            for x in values {
+                for y in values {
+                    let (a, b) = (Tagged::from_integer(x), Tagged::from_integer(y));
+
+                    assert_eq!(a.checked_add(b), check(x.checked_add(y)));
+                    assert_eq!(a.checked_sub(b), check(x.checked_sub(y)));
+                    assert_eq!(a.checked_mul(b), check(x.checked_mul(y)));
+                    assert_eq!(a.checked_div(b), check(x.checked_div(y)));
+                    assert_eq!(a.checked_rem(b), check(x.checked_rem(y)));
+                }
+
+                assert_eq!(
+                    Tagged::from_integer(x).checked_neg(),
+                    check(x.checked_neg())
+                );
+            }
+        }
+
+        #[test]
+        fn reject_pointers() {
+            let pointer = Tagged::from_pointer(core::ptr::dangling::<u16>());
+            let integer = Tagged::from_integer(1);
+
+            assert_eq!(pointer.checked_add(integer), None);
+            assert_eq!(integer.checked_sub(pointer), None);
+            assert_eq!(pointer.checked_mul(pointer), None);
+            assert_eq!(integer.checked_div(pointer), None);
+            assert_eq!(pointer.checked_rem(integer), None);
+            assert_eq!(pointer.checked_neg(), None);
+        }
+
+        #[test]
+        fn reject_division_by_zero() {
+            let zero = Tagged::from_integer(0);
+
+            assert_eq!(Tagged::from_integer(1).checked_div(zero), None);
+            assert_eq!(Tagged::from_integer(1).checked_rem(zero), None);
+        }
+    }
+}